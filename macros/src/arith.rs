@@ -0,0 +1,137 @@
+// macros/src/arith.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Factoring of u128s. The modular arithmetic and the primality test
+// are those of typenum-prime itself, from the typenum-prime-runtime
+// crate, so that the macro and `is_prime_u128` always agree.
+
+use runtime::{add_mod_u128 as add_mod, is_prime_u128 as is_prime, mul_mod_u128 as mul_mod};
+
+
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+
+// The smallest divisor of a composite n greater than 1, if it is
+// below a bound that keeps trial division quick.
+pub fn small_divisor(n: u128) -> Option<u128> {
+    match n {
+        0 | 1 => None,
+        _ => (2..1 << 20).take_while(|d| d * d <= n).find(|d| n % d == 0),
+    }
+}
+
+
+// The prime factors of n > 0 with their multiplicities, or None if a
+// composite cofactor resists Pollard's rho method.
+pub fn factor(mut n: u128) -> Option<Vec<(u128, u32)>> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d < 1 << 16 && d * d <= n {
+        if n % d == 0 {
+            let mut k = 0;
+            while n % d == 0 {
+                n /= d;
+                k += 1;
+            }
+            factors.push((d, k));
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    let mut pending = vec![n];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            match factors.iter_mut().find(|f| f.0 == m) {
+                Some(f) => f.1 += 1,
+                None => factors.push((m, 1)),
+            }
+            continue;
+        }
+        let d = rho(m)?;
+        pending.push(d);
+        pending.push(m / d);
+    }
+    factors.sort_unstable();
+    Some(factors)
+}
+
+// A nontrivial divisor of the odd composite n, by Brent's variant of
+// Pollard's rho method, giving up after about a million steps.
+fn rho(n: u128) -> Option<u128> {
+    // Only a sequence that cycles modulo all prime factors of n at
+    // once is worth retrying with another constant.
+    for c in 1..16 {
+        match rho_with(n, c) {
+            Some(d) if d == n => {},
+            result => return result,
+        }
+    }
+    None
+}
+
+// Brent's cycle search for x -> x^2 + c, which finds n itself when the
+// sequence cycles modulo n and all its prime factors at once.
+fn rho_with(n: u128, c: u128) -> Option<u128> {
+    const BATCH: u64 = 128;
+    let f = |x: u128| add_mod(mul_mod(x, x, n), c, n);
+    let (mut y, mut product, mut run) = (2, 1, 1);
+    while run < 1 << 18 {
+        let x = y;
+        for _ in 0..run {
+            y = f(y);
+        }
+        // Take the gcd of the product of a batch of differences at
+        // once, and retrace the batch if it is not 1.
+        let mut k = 0;
+        while k < run {
+            let start = y;
+            for _ in 0..BATCH.min(run - k) {
+                y = f(y);
+                product = mul_mod(product, x.abs_diff(y), n);
+            }
+            if gcd(product, n) != 1 {
+                let mut y = start;
+                return loop {
+                    y = f(y);
+                    match gcd(x.abs_diff(y), n) {
+                        1 => {},
+                        g => break Some(g),
+                    }
+                };
+            }
+            k += BATCH;
+        }
+        run *= 2;
+    }
+    None
+}
+
+#[test]
+fn test_factor() {
+    assert_eq!(factor(1), Some(vec![]));
+    assert_eq!(factor(360), Some(vec![(2, 3), (3, 2), (5, 1)]));
+    assert_eq!(factor((1 << 61) - 2),
+               Some(vec![(2, 1), (3, 2), (5, 2), (7, 1), (11, 1), (13, 1), (31, 1), (41, 1),
+                         (61, 1), (151, 1), (331, 1), (1321, 1)]));
+    // 2^67 - 1, Cole's factorization
+    assert_eq!(factor((1 << 67) - 1),
+               Some(vec![(193_707_721, 1), (761_838_257_287, 1)]));
+    let (p, q) = (4_294_967_291, 18_446_744_073_709_551_557);
+    assert_eq!(factor(p * q), Some(vec![(p, 1), (q, 1)]));
+}
@@ -0,0 +1,89 @@
+// macros/src/certificate.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Pocklington certificates of primality, written out as the types
+// that `typenum_prime::CertifiedPrime` checks.
+//
+// For a prime n, n-1 = F*R where F is a product of known prime powers
+// with F > R. The certificate holds R, a witness x, and for each
+// prime q dividing F the inverse of x^((n-1)/q) - 1 modulo n. The
+// witness and the inverses are in Montgomery form, multiplied by 16^W
+// modulo n, where W is the number of hexadecimal digits of 4n.
+
+use arith::factor;
+use runtime::{add_mod_u128 as add_mod, mul_mod_u128 as mul_mod, pow_mod_u128 as pow_mod};
+
+
+// Primes below this are left to trial division by `Prime`.
+const SMALL: u128 = 1 << 10;
+
+// The placeholder for the path of the typenum-prime crate, which the
+// macro replaces with the `$crate` it is given.
+pub const CRATE: &str = "__typenum_prime";
+
+
+// The type of the prime n, which implements `CertifiedPrime`.
+pub fn certified_prime(n: u128) -> Result<String, String> {
+    if n < SMALL {
+        return Ok(typenum_type(n));
+    }
+    let factors = factor(n - 1)
+        .ok_or_else(|| format!("`{}` is prime, but `{}` is too hard to factor to prove it", n, n - 1))?;
+
+    // Take prime powers from the largest primes down until F > R,
+    // which keeps the number of exponentiations small.
+    let mut part = Vec::new();
+    let mut f = 1;
+    for &(q, e) in factors.iter().rev() {
+        let mut k = 0;
+        while k < e && f <= (n - 1) / f {
+            f *= q;
+            k += 1;
+        }
+        if k > 0 {
+            part.push((q, k));
+        }
+    }
+    let r = (n - 1) / f;
+
+    // 16^W modulo n, by doubling.
+    let digits = (128 - n.leading_zeros() + 2).div_ceil(4);
+    let mont = (0..4 * digits).fold(1, |a, _| add_mod(a, a, n));
+
+    // Every q must divide the multiplicative order of x.
+    let x = (2..n)
+        .find(|&x| part.iter().all(|&(q, _)| pow_mod(x, (n - 1) / q, n) != 1))
+        .expect("a prime has a primitive root");
+
+    // The smallest q goes first, as the checker raises to it once more
+    // to get x^(n-1).
+    let mut list = format!("{}::typenum::ATerm", CRATE);
+    for &(q, k) in part.iter() {
+        let d = pow_mod(x, (n - 1) / q, n);
+        let inverse = pow_mod(d - 1, n - 2, n);
+        list = format!("{0}::typenum::TArr<{0}::private::PocklingtonFactor<{1}, {2}, {3}>, {4}>",
+                       CRATE, certified_prime(q)?, typenum_type(k as u128),
+                       typenum_type(mul_mod(inverse, mont, n)), list);
+    }
+    Ok(format!("{0}::Certified<{1}, {0}::private::Pocklington<{2}, {3}, {4}>>",
+               CRATE, typenum_type(n), typenum_type(mul_mod(x, mont, n)), typenum_type(r), list))
+}
+
+
+// The path of the typenum type for n, most significant bit
+// outermost.
+pub fn typenum_type(n: u128) -> String {
+    let mut path = format!("{}::typenum::UTerm", CRATE);
+    for bit in (0..128 - n.leading_zeros()).rev() {
+        let b = if (n >> bit) & 1 == 1 { "B1" } else { "B0" };
+        path = format!("{0}::typenum::UInt<{1}, {0}::typenum::{2}>", CRATE, path, b);
+    }
+    path
+}
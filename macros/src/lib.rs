@@ -0,0 +1,107 @@
+// macros/src/lib.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Procedural macros for the `typenum-prime` crate.
+//!
+//! Use them through the `macros` feature of `typenum-prime` rather
+//! than depending on this crate directly.
+
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+extern crate typenum_prime_runtime as runtime;
+
+use proc_macro::{Group, Span, TokenStream, TokenTree};
+
+mod arith;
+mod certificate;
+
+
+/// Expand a prime integer literal to a type that implements
+/// `CertifiedPrime`.
+///
+/// This is the implementation of `typenum_prime::prime!`, which
+/// passes its `$crate` ahead of the literal, as in
+/// `prime!($crate; 2_147_483_647)`, so that the expansion names
+/// `typenum-prime` however it is imported. See that macro for the
+/// documentation.
+#[proc_macro]
+pub fn prime(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let krate = match (tokens.next(), tokens.next()) {
+        (Some(krate), Some(TokenTree::Punct(ref semi))) if semi.as_char() == ';' => krate,
+        _ => return compile_error("expected `$crate;` before the literal", Span::call_site()),
+    };
+    let (n, span) = match parse_literal(tokens.collect()) {
+        Ok(literal) => literal,
+        Err((message, span)) => return compile_error(&message, span),
+    };
+    if !runtime::is_prime_u128(n) {
+        let message = match arith::small_divisor(n) {
+            Some(d) => format!("`{}` is not prime; it is divisible by `{}`", n, d),
+            None => format!("`{}` is not prime", n),
+        };
+        return compile_error(&message, span);
+    }
+    match certificate::certified_prime(n) {
+        Ok(path) => with_crate(path.parse().unwrap(), &krate),
+        Err(message) => compile_error(&message, span),
+    }
+}
+
+
+// Replace the placeholder `certificate::CRATE` in generated paths
+// with the `$crate` of the caller.
+fn with_crate(tokens: TokenStream, krate: &TokenTree) -> TokenStream {
+    tokens.into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ref ident) if ident.to_string() == certificate::CRATE => krate.clone(),
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), with_crate(group.stream(), krate));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            },
+            token => token,
+        })
+        .collect()
+}
+
+
+// Parse the macro input as a single unsigned integer literal, with
+// optional underscores, radix prefix and integer suffix.
+fn parse_literal(input: TokenStream) -> Result<(u128, Span), (String, Span)> {
+    let mut tokens = input.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(TokenTree::Group(group)), None) => return parse_literal(group.stream()),
+        (Some(token), _) => return Err(("expected an integer literal".to_string(), token.span())),
+        (None, _) => return Err(("expected an integer literal".to_string(), Span::call_site())),
+    };
+    let span = literal.span();
+    let text: String = literal.to_string().chars().filter(|&c| c != '_').collect();
+    let (digits, radix) = [("0x", 16), ("0o", 8), ("0b", 2)].iter()
+        .find_map(|&(prefix, radix)| text.strip_prefix(prefix).map(|digits| (digits, radix)))
+        .unwrap_or((&text[..], 10));
+    let digits = ["u128", "u64", "u32", "u16", "u8", "usize"].iter()
+        .find(|suffix| digits.ends_with(*suffix))
+        .map_or(digits, |suffix| &digits[..digits.len() - suffix.len()]);
+    match u128::from_str_radix(digits, radix) {
+        Ok(n) => Ok((n, span)),
+        Err(_) => Err((format!("expected an unsigned integer literal, found `{}`", literal), span)),
+    }
+}
+
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let tokens: TokenStream = format!("compile_error!({:?})", message).parse().unwrap();
+    tokens.into_iter()
+        .map(|mut token| { token.set_span(span); token })
+        .collect()
+}
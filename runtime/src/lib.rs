@@ -0,0 +1,355 @@
+// runtime/src/lib.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Runtime primality testing for the `typenum-prime` crate.
+//!
+//! Use it through `typenum-prime`, which re-exports `is_prime_u64` and
+//! `is_prime_u128`, rather than depending on this crate directly. It
+//! is a crate of its own so that `typenum-prime-macros` accepts
+//! exactly the primes that `is_prime_u128` does.
+
+// Runtime primality testing with Miller-Rabin. These functions answer
+// the same question as the IsPrime type operator, but for ordinary
+// integers, and they can be evaluated in const contexts. The test is
+// deterministic below BASES_BOUND, which covers every u64, and a
+// Baillie-PSW probable prime test above it.
+
+#![no_std]
+#![warn(missing_docs)]
+
+
+// The first 13 primes. Every integer below 3_317_044_064_679_887_385_961_981
+// that passes the strong probable prime test for all of these bases
+// is prime, which covers the whole range of u64.
+const BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+// The smallest strong pseudoprime to all of BASES.
+const BASES_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+
+/// Test whether `n` is prime.
+///
+/// This is deterministic Miller-Rabin using the first 13 primes as
+/// bases, which is exact for every `u64`. It agrees with
+/// `<N as IsPrime>::Output` for every `typenum` integer `N` that fits
+/// in a `u64`.
+///
+/// ```
+/// # extern crate typenum_prime_runtime;
+/// # use typenum_prime_runtime::is_prime_u64;
+/// # fn main() {
+/// const P: bool = is_prime_u64(2_305_843_009_213_693_951);
+/// assert!(P);
+/// assert!(!is_prime_u64(91));
+/// # }
+/// ```
+pub const fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        let p = BASES[i];
+        if n == p {
+            return true;
+        }
+        if n % p == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    while i < BASES.len() {
+        if !strong_probable_prime_u64(n, d, s, BASES[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+
+/// Test whether `n` is probably prime.
+///
+/// Unlike `is_prime_u64`, this test is not deterministic for every
+/// input. It is exact below 3_317_044_064_679_887_385_961_981, about
+/// 3.3e24, where it is the same deterministic Miller-Rabin test.
+/// Integers beyond that also have to pass a strong Lucas test, making
+/// the combination a Baillie-PSW test. No composite that passes
+/// Baillie-PSW is known, but none has been proven not to exist below
+/// 2^128.
+///
+/// `IsPrime` cannot tell the difference: trial division at compile
+/// time is only practical for integers of about 20 bits, so the two
+/// tests are only ever compared far below the bound.
+///
+/// ```
+/// # extern crate typenum_prime_runtime;
+/// # use typenum_prime_runtime::is_prime_u128;
+/// # fn main() {
+/// const P: bool = is_prime_u128((1 << 127) - 1);
+/// assert!(P);
+/// assert!(!is_prime_u128(3_317_044_064_679_887_385_961_981));
+/// # }
+/// ```
+pub const fn is_prime_u128(n: u128) -> bool {
+    if n <= u64::MAX as u128 {
+        return is_prime_u64(n as u64);
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n % BASES[i] as u128 == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    while i < BASES.len() {
+        if !strong_probable_prime_u128(n, d, s, BASES[i] as u128) {
+            return false;
+        }
+        i += 1;
+    }
+    n < BASES_BOUND || strong_lucas_probable_prime(n)
+}
+
+
+// Miller-Rabin test of odd n with base a, where n-1 == d*2^s and d
+// is odd.
+const fn strong_probable_prime_u64(n: u64, d: u64, s: u32, a: u64) -> bool {
+    let mut x = pow_mod_u64(a % n, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    let mut r = 1;
+    while r < s {
+        x = mul_mod_u64(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+        r += 1;
+    }
+    false
+}
+
+// The modular arithmetic helpers are public for typenum-prime and its
+// macros, but they are not part of the API.
+#[doc(hidden)]
+pub const fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+#[doc(hidden)]
+pub const fn pow_mod_u64(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u64(result, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+
+// Miller-Rabin test of odd n with base a, where n-1 == d*2^s and d
+// is odd.
+const fn strong_probable_prime_u128(n: u128, d: u128, s: u32, a: u128) -> bool {
+    let mut x = pow_mod_u128(a % n, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    let mut r = 1;
+    while r < s {
+        x = mul_mod_u128(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+        r += 1;
+    }
+    false
+}
+
+// (a+b)%m for a,b < m, without overflowing.
+#[doc(hidden)]
+pub const fn add_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+// (a-b)%m for a,b < m.
+const fn sub_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b { a - b } else { m - (b - a) }
+}
+
+// (a*b)%m for a,b < m. Products of residues of moduli beyond u64 are
+// formed by double-and-add, so that nothing overflows.
+#[doc(hidden)]
+pub const fn mul_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if m <= u64::MAX as u128 {
+        return a * b % m;
+    }
+    let mut result = 0;
+    let mut shift = 128 - b.leading_zeros();
+    while shift > 0 {
+        shift -= 1;
+        result = add_mod_u128(result, result, m);
+        if (b >> shift) & 1 == 1 {
+            result = add_mod_u128(result, a, m);
+        }
+    }
+    result
+}
+
+#[doc(hidden)]
+pub const fn pow_mod_u128(mut base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u128(result, base, m);
+        }
+        base = mul_mod_u128(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// x/2 mod odd m, for x < m.
+const fn half_mod_u128(x: u128, m: u128) -> u128 {
+    if x & 1 == 0 { x / 2 } else { x / 2 + m / 2 + 1 }
+}
+
+// The Jacobi symbol (a/n) for odd n.
+const fn jacobi(mut a: u128, mut n: u128) -> i32 {
+    let mut result = 1;
+    a %= n;
+    while a != 0 {
+        while a & 1 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        let t = a;
+        a = n;
+        n = t;
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 { result } else { 0 }
+}
+
+const fn is_square(n: u128) -> bool {
+    // Newton's method, starting above the square root.
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    x * x == n
+}
+
+// Strong Lucas probable prime test of odd n with parameters chosen by
+// Selfridge's method: D is the first of 5, -7, 9, -11, ... with
+// (D/n) == -1, P == 1 and Q == (1-D)/4. n must be greater than
+// u64::MAX and not divisible by any of BASES.
+const fn strong_lucas_probable_prime(n: u128) -> bool {
+    // No suitable D exists for perfect squares.
+    if is_square(n) {
+        return false;
+    }
+    let mut abs_d: u128 = 5;
+    let mut negative = false;
+    loop {
+        let d_mod_n = if negative { n - abs_d } else { abs_d };
+        let j = jacobi(d_mod_n, n);
+        if j == -1 {
+            break;
+        }
+        // n shares a factor with D, which is smaller than n.
+        if j == 0 {
+            return false;
+        }
+        abs_d += 2;
+        negative = !negative;
+    }
+    let d = if negative { n - abs_d } else { abs_d };
+    // Q == (1-D)/4, which is positive when D is negative.
+    let q = if negative { (abs_d + 1) / 4 } else { n - (abs_d - 1) / 4 };
+
+    // n+1 == k*2^s with k odd
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+
+    // Compute U_k, V_k and Q^k, reading k from its most significant
+    // bit, starting from U_1 == 1, V_1 == P == 1.
+    let mut u: u128 = 1;
+    let mut v: u128 = 1;
+    let mut qk = q;
+    let mut bit = 127 - k.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        // doubling: U_2j == U_j*V_j, V_2j == V_j^2 - 2*Q^j
+        u = mul_mod_u128(u, v, n);
+        v = sub_mod_u128(mul_mod_u128(v, v, n), add_mod_u128(qk, qk, n), n);
+        qk = mul_mod_u128(qk, qk, n);
+        if (k >> bit) & 1 == 1 {
+            // increment: U_j+1 == (P*U_j + V_j)/2, V_j+1 == (D*U_j + P*V_j)/2
+            let next_u = half_mod_u128(add_mod_u128(u, v, n), n);
+            let next_v = half_mod_u128(add_mod_u128(mul_mod_u128(d, u, n), v, n), n);
+            u = next_u;
+            v = next_v;
+            qk = mul_mod_u128(qk, q, n);
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+    let mut r = 1;
+    while r < s {
+        v = sub_mod_u128(mul_mod_u128(v, v, n), add_mod_u128(qk, qk, n), n);
+        if v == 0 {
+            return true;
+        }
+        qk = mul_mod_u128(qk, qk, n);
+        r += 1;
+    }
+    false
+}
+
+#[test]
+fn test_is_prime_u128() {
+    // Mersenne primes and composites beyond u64
+    assert!(is_prime_u128((1 << 89) - 1));
+    assert!(is_prime_u128((1 << 107) - 1));
+    assert!(is_prime_u128((1 << 127) - 1));
+    assert!(!is_prime_u128((1 << 67) - 1));
+    assert!(!is_prime_u128((1 << 101) - 1));
+    assert!(!is_prime_u128(u128::MAX));
+    // strong pseudoprimes to the first 9 and the first 12 of BASES
+    assert!(!is_prime_u128(3_825_123_056_546_413_051));
+    assert!(!is_prime_u128(318_665_857_834_031_151_167_461));
+    // strong pseudoprime to every base in BASES
+    assert!(!is_prime_u128(BASES_BOUND));
+    // squares of primes and products of two large primes
+    assert!(!is_prime_u128(18_446_744_073_709_551_557 * 18_446_744_073_709_551_557));
+    assert!(!is_prime_u128(18_446_744_073_709_551_557 * 18_446_744_073_709_551_533));
+    assert!(is_prime_u128(340_282_366_920_938_463_463_374_607_431_768_211_297));
+}
@@ -0,0 +1,121 @@
+// src/barrett.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use typenum::marker_traits::Unsigned;
+
+use BarrettConstants;
+
+
+/// A reducer for the integers modulo `P` by Barrett's method.
+///
+/// The reciprocal μ = floor(2^128 / `P`) is computed by
+/// `BarrettConstants`, so reducing needs two multiplications and no
+/// division. Unlike `Montgomery`, values need no conversion in or out.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U97;
+/// # use typenum_prime::Barrett;
+/// # fn main() {
+/// let b = Barrett::<U97>::new();
+/// assert_eq!(b.reduce(1_000_000), 1_000_000 % 97);
+/// assert_eq!(b.reduce(u128::MAX), (u128::MAX % 97) as u64);
+/// assert_eq!(b.mul_mod(12, 90), 13);
+/// # }
+/// ```
+pub struct Barrett<P> where P: BarrettConstants {
+    prime: PhantomData<P>,
+}
+
+impl<P> Barrett<P> where P: BarrettConstants {
+    /// floor(2^128 / `P`).
+    pub const MU: u128 = (<P as BarrettConstants>::MuHigh::U64 as u128) << 64
+        | <P as BarrettConstants>::MuLow::U64 as u128;
+
+    /// Create the reducer.
+    pub const fn new() -> Self {
+        Barrett { prime: PhantomData }
+    }
+
+    /// `x` mod `P`.
+    pub const fn reduce(self, x: u128) -> u64 {
+        let p = P::MODULUS as u128;
+        // The estimate of x/P is low by at most 2.
+        let q = mul_high(x, Self::MU);
+        let mut r = x - q * p;
+        while r >= p {
+            r -= p;
+        }
+        r as u64
+    }
+
+    /// `a`·`b` mod `P`.
+    pub const fn mul_mod(self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+}
+
+// The high 128 bits of the 256-bit product of a and b.
+const fn mul_high(a: u128, b: u128) -> u128 {
+    const LOW: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & LOW);
+    let (b1, b0) = (b >> 64, b & LOW);
+    let (mid1, mid2) = (a1 * b0, a0 * b1);
+    let carry = ((a0 * b0) >> 64) + (mid1 & LOW) + (mid2 & LOW);
+    a1 * b1 + (mid1 >> 64) + (mid2 >> 64) + (carry >> 64)
+}
+
+impl<P> Clone for Barrett<P> where P: BarrettConstants {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Barrett<P> where P: BarrettConstants {}
+
+impl<P> Default for Barrett<P> where P: BarrettConstants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> fmt::Debug for Barrett<P> where P: BarrettConstants {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Barrett(mod {})", P::MODULUS)
+    }
+}
+
+#[test]
+fn test_agrees_with_rem() {
+    use typenum::consts::{U2, U3, U97, U1021};
+    fn check<P: BarrettConstants>() {
+        let b = Barrett::<P>::new();
+        let p = P::MODULUS;
+        let mut x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        for _ in 0..1000 {
+            assert_eq!(b.reduce(x) as u128, x % p as u128);
+            x = x.wrapping_mul(0x2545_f491_4f6c_dd1d).wrapping_add(1);
+        }
+        for x in [0, 1, p as u128 - 1, p as u128, u64::MAX as u128, u128::MAX] {
+            assert_eq!(b.reduce(x) as u128, x % p as u128);
+        }
+        for a in (0..p).step_by(1 + p as usize / 40) {
+            assert_eq!(b.mul_mod(a, u64::MAX - a), ((a as u128 * (u64::MAX - a) as u128) % p as u128) as u64);
+        }
+    }
+    check::<U2>();
+    check::<U3>();
+    check::<U97>();
+    check::<U1021>();
+}
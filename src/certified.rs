@@ -0,0 +1,39 @@
+// src/certified.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::marker::PhantomData;
+
+
+/// The integer `N` together with a certificate `C` of its primality.
+///
+/// `Certified` types are written by the `prime!` macro, which finds
+/// the certificate, and they implement `CertifiedPrime` with `Value`
+/// `N` when the certificate checks out. The certificate types are not
+/// part of the public API.
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::array::{ATerm, TArr};
+/// # use typenum::consts::*;
+/// # use typenum::operator_aliases::Sum;
+/// # use typenum_prime::{Certified, CertifiedPrime};
+/// # use typenum_prime::private::{Pocklington, PocklingtonFactor};
+/// fn modulus<P: CertifiedPrime>() {}
+/// // the certificate of 1031, attached to 1037 = 17*61
+/// type Forged = Certified<Sum<U1024,U13>,
+///                         Pocklington<U135,U10,TArr<PocklingtonFactor<U103,U1,U56>,ATerm>>>;
+/// # fn main() {
+/// modulus::<Forged>(); // error
+/// # }
+/// ```
+pub struct Certified<N,C> {
+    prime: PhantomData<(N,C)>,
+}
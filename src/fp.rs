@@ -0,0 +1,239 @@
+// src/fp.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use CertifiedPrime;
+use private::ModulusU64;
+use runtime::{mul_mod_u64, pow_mod_u64};
+
+
+/// An element of the prime field of integers modulo `P`.
+///
+/// Every nonzero element has a multiplicative inverse only because `P`
+/// is prime, which is what makes `Div` and `inv` well defined. `P` is
+/// a `CertifiedPrime`, so it may be a `Prime` integer or a larger prime
+/// written with `prime!`.
+///
+/// Residues are stored in a `u64`, and so are those of `Montgomery`
+/// and `Barrett`. A prime `P` that does not fit in a `u64` is a
+/// compile error as soon as any of these types does arithmetic.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U101;
+/// # use typenum_prime::Fp;
+/// # fn main() {
+/// type F = Fp<U101>;
+/// let a = F::new(12);
+/// let b = F::new(100);
+/// assert_eq!((a + b).value(), 11);
+/// assert_eq!((a * b).value(), 89);
+/// assert_eq!((-a).value(), 89);
+/// assert_eq!(a / a, F::ONE);
+/// assert_eq!(a * a.inv().unwrap(), F::ONE);
+/// assert_eq!(F::ZERO.inv(), None);
+/// # }
+/// ```
+pub struct Fp<P> where P: CertifiedPrime + ModulusU64 {
+    value: u64,
+    prime: PhantomData<P>,
+}
+
+impl<P> Fp<P> where P: CertifiedPrime + ModulusU64 {
+    /// The additive identity.
+    pub const ZERO: Self = Fp { value: 0, prime: PhantomData };
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Fp { value: 1, prime: PhantomData };
+
+    /// The element congruent to `value` modulo `P`.
+    pub const fn new(value: u64) -> Self {
+        Fp { value: value % P::MODULUS, prime: PhantomData }
+    }
+
+    /// The representative of `self` in the range `0..P`.
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Whether `self` is zero.
+    pub const fn is_zero(self) -> bool {
+        self.value == 0
+    }
+
+    /// `self` raised to the power `exp`.
+    pub const fn pow(self, exp: u64) -> Self {
+        Fp { value: pow_mod_u64(self.value, exp, P::MODULUS), prime: PhantomData }
+    }
+
+    /// The multiplicative inverse of `self`, or `None` if `self` is
+    /// zero.
+    ///
+    /// By Fermat's little theorem, the inverse of a nonzero `a` is
+    /// `a^(P-2)`.
+    pub const fn inv(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.pow(P::MODULUS - 2))
+        }
+    }
+}
+
+impl<P> Clone for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Fp<P> where P: CertifiedPrime + ModulusU64 {}
+
+impl<P> PartialEq for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P> Eq for Fp<P> where P: CertifiedPrime + ModulusU64 {}
+
+impl<P> Hash for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<P> Default for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P> fmt::Debug for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, P::MODULUS)
+    }
+}
+
+impl<P> fmt::Display for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<P> From<u64> for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<P> Add for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Compare against P-b rather than computing a+b, which can
+        // overflow when P is close to 2^64.
+        let gap = P::MODULUS - rhs.value;
+        let value = if self.value >= gap { self.value - gap } else { self.value + rhs.value };
+        Fp { value, prime: PhantomData }
+    }
+}
+
+impl<P> Sub for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<P> Neg for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.value {
+            0 => self,
+            v => Fp { value: P::MODULUS - v, prime: PhantomData },
+        }
+    }
+}
+
+impl<P> Mul for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Fp { value: mul_mod_u64(self.value, rhs.value, P::MODULUS), prime: PhantomData }
+    }
+}
+
+impl<P> Div for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv().expect("attempt to divide by zero")
+    }
+}
+
+impl<P> AddAssign for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<P> SubAssign for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<P> MulAssign for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<P> DivAssign for Fp<P> where P: CertifiedPrime + ModulusU64 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[test]
+fn test_field_axioms() {
+    use typenum::consts::{U2, U7};
+    type F = Fp<U7>;
+    for a in 0..7 {
+        let a = F::new(a);
+        assert_eq!(a + F::ZERO, a);
+        assert_eq!(a * F::ONE, a);
+        assert_eq!(a + -a, F::ZERO);
+        if !a.is_zero() {
+            assert_eq!(a * a.inv().unwrap(), F::ONE);
+        }
+        for b in 0..7 {
+            let b = F::new(b);
+            assert_eq!(a + b, F::new(a.value() + b.value()));
+            assert_eq!(a * b, F::new(a.value() * b.value()));
+            assert_eq!(a - b + b, a);
+            if !b.is_zero() {
+                assert_eq!(a / b * b, a);
+            }
+        }
+    }
+    assert_eq!(Fp::<U2>::ONE.inv(), Some(Fp::ONE));
+}
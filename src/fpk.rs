@@ -0,0 +1,363 @@
+// src/fpk.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use generic_array::{ArrayLength, GenericArray};
+use generic_array::sequence::GenericSequence;
+use typenum::marker_traits::Unsigned;
+
+use private::{FillTail, ModulusU64, PrivateFpModulus};
+use {Fp, FpIrreducible, Prime};
+
+
+/// **Trait** for the polynomials that define extension fields of
+/// GF(`P`).
+///
+/// This trait is automatically implemented for `typenum` type-level
+/// arrays of coefficients that are `FpIrreducible<P>`.
+pub trait ExtensionModulus<P>: FpIrreducible<P> + FillTail<P> where P: Prime + ModulusU64 {
+    /// The degree of the polynomial, which is the degree of the
+    /// extension.
+    type Degree: ArrayLength;
+}
+
+impl<A,P> ExtensionModulus<P> for A
+    where A: FpIrreducible<P> + PrivateFpModulus<P> + FillTail<P>,
+          P: Prime + ModulusU64,
+          <A as PrivateFpModulus<P>>::Degree: ArrayLength {
+    type Degree = <A as PrivateFpModulus<P>>::Degree;
+}
+
+
+/// An element of the finite field GF(`P`^k), where k is the degree of
+/// the polynomial `Poly`.
+///
+/// The field is the polynomials over GF(`P`) modulo `Poly`, whose
+/// irreducibility is checked at compile time. `Poly` is a `typenum`
+/// type-level array of coefficients, constant term first, as for
+/// `IsIrreducibleFp`. Elements are stored as their k coefficients.
+///
+/// `Fpk` is `Copy` whenever its `GenericArray` of coefficients is,
+/// which is the case for any concrete `Poly`.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::{Fp, Fpk};
+/// # fn main() {
+/// // GF(9) as GF(3) with a square root of -1
+/// type F9 = Fpk<U3, tarr![U1, U0, U1]>;
+/// let i = F9::x();
+/// assert_eq!(i * i, -F9::one());
+/// let a = F9::from_coeffs(&[1, 2]); // 1 + 2i
+/// assert_eq!(a.frobenius(), F9::from_coeffs(&[1, 1])); // the conjugate
+/// assert_eq!(a * a.inv().unwrap(), F9::one());
+/// assert_eq!(a.pow(8), F9::one());
+/// assert_eq!(F9::from(Fp::new(2)), F9::from_coeffs(&[2]));
+/// # }
+/// ```
+///
+/// The polynomial must be irreducible.
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::Fpk;
+/// # fn main() {
+/// let x = Fpk::<U5, tarr![U1, U0, U1]>::x();
+/// # }
+/// ```
+pub struct Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    // The coefficients of the residue, constant term first.
+    coeffs: GenericArray<Fp<P>, Poly::Degree>,
+    modulus: PhantomData<Poly>,
+}
+
+impl<P,Poly> Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    /// The additive identity.
+    pub fn zero() -> Self {
+        Fpk { coeffs: GenericArray::generate(|_| Fp::ZERO), modulus: PhantomData }
+    }
+
+    /// The multiplicative identity.
+    pub fn one() -> Self {
+        Self::from(Fp::ONE)
+    }
+
+    /// The residue of x, which is a root of `Poly`.
+    pub fn x() -> Self {
+        Self::from_coeffs(&[0, 1])
+    }
+
+    /// The residue of the polynomial with the coefficients `coeffs`,
+    /// constant term first. There may be any number of them.
+    pub fn from_coeffs(coeffs: &[u64]) -> Self {
+        let tail = Self::tail();
+        let mut result = Self::zero();
+        for &c in coeffs.iter().rev() {
+            result.mul_x(&tail);
+            result.coeffs[0] += Fp::new(c);
+        }
+        result
+    }
+
+    /// The coefficients of the representative of `self` of degree less
+    /// than k, constant term first.
+    pub fn coeffs(&self) -> &[Fp<P>] {
+        &self.coeffs
+    }
+
+    /// Whether `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+
+    /// `self` raised to the power `exp`.
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_ref(&base);
+            }
+            base = base.mul_ref(&base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The Frobenius automorphism, `self` raised to the power `P`.
+    ///
+    /// It fixes the elements of GF(`P`), and applying it k times is the
+    /// identity.
+    pub fn frobenius(&self) -> Self {
+        self.pow(P::MODULUS)
+    }
+
+    /// The multiplicative inverse of `self`, or `None` if `self` is
+    /// zero.
+    ///
+    /// The product of the conjugates of `self` other than itself, under
+    /// the Frobenius automorphism, is `self` times the norm of `self`.
+    /// The norm lies in GF(`P`), where it is inverted.
+    pub fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let mut conjugate = self.clone();
+        let mut others = Self::one();
+        for _ in 1..Poly::Degree::USIZE {
+            conjugate = conjugate.frobenius();
+            others = others.mul_ref(&conjugate);
+        }
+        let norm = self.mul_ref(&others).coeffs[0];
+        Some(others.scale(norm.inv()?))
+    }
+
+    // The monic tail of the modulus: x^k == -tail.
+    fn tail() -> GenericArray<Fp<P>, Poly::Degree> {
+        let mut raw = GenericArray::<u64, Poly::Degree>::generate(|_| 0);
+        Poly::fill_tail(&mut raw);
+        GenericArray::generate(|i| Fp::new(raw[i]))
+    }
+
+    // Multiply by x in place.
+    fn mul_x(&mut self, tail: &[Fp<P>]) {
+        let k = self.coeffs.len();
+        if k == 0 {
+            return;
+        }
+        let carry = self.coeffs[k - 1];
+        for j in (1..k).rev() {
+            self.coeffs[j] = self.coeffs[j - 1];
+        }
+        self.coeffs[0] = Fp::ZERO;
+        for (c, &t) in self.coeffs.iter_mut().zip(tail) {
+            *c -= carry * t;
+        }
+    }
+
+    // Multiply by an element of GF(P).
+    fn scale(mut self, factor: Fp<P>) -> Self {
+        for c in self.coeffs.iter_mut() {
+            *c *= factor;
+        }
+        self
+    }
+
+    // The product of self and rhs, by Horner's rule on the coefficients
+    // of rhs from the highest:
+    //     a*(b + x*bs) == x*(a*bs) + b*a
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        let tail = Self::tail();
+        let mut result = Self::zero();
+        for &b in rhs.coeffs.iter().rev() {
+            result.mul_x(&tail);
+            for (r, &a) in result.coeffs.iter_mut().zip(self.coeffs.iter()) {
+                *r += a * b;
+            }
+        }
+        result
+    }
+}
+
+impl<P,Poly> Clone for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn clone(&self) -> Self {
+        Fpk { coeffs: self.coeffs.clone(), modulus: PhantomData }
+    }
+}
+
+impl<P,Poly> Copy for Fpk<P,Poly>
+    where P: Prime + ModulusU64,
+          Poly: ExtensionModulus<P>,
+          <Poly::Degree as ArrayLength>::ArrayType<Fp<P>>: Copy {}
+
+impl<P,Poly> PartialEq for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<P,Poly> Eq for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {}
+
+impl<P,Poly> Hash for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coeffs.hash(state);
+    }
+}
+
+impl<P,Poly> Default for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P,Poly> fmt::Debug for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self, P::MODULUS)
+    }
+}
+
+/// Formats the representative as a polynomial in x, such as `2x^2 + 1`.
+impl<P,Poly> fmt::Display for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        let mut first = true;
+        for (i, c) in self.coeffs.iter().enumerate().rev().filter(|(_, c)| !c.is_zero()) {
+            if !first {
+                f.write_str(" + ")?;
+            }
+            first = false;
+            match (i, c.value()) {
+                (0, v) => write!(f, "{}", v)?,
+                (1, 1) => f.write_str("x")?,
+                (1, v) => write!(f, "{}x", v)?,
+                (i, 1) => write!(f, "x^{}", i)?,
+                (i, v) => write!(f, "{}x^{}", v, i)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P,Poly> From<Fp<P>> for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn from(value: Fp<P>) -> Self {
+        let mut result = Self::zero();
+        if let Some(c) = result.coeffs.first_mut() {
+            *c = value;
+        }
+        result
+    }
+}
+
+impl<P,Poly> Add for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<P,Poly> Sub for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<P,Poly> Neg for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for c in self.coeffs.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+
+impl<P,Poly> Mul for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_ref(&rhs)
+    }
+}
+
+impl<P,Poly> Div for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv().expect("attempt to divide by zero")
+    }
+}
+
+impl<P,Poly> AddAssign for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, &b) in self.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<P,Poly> SubAssign for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, &b) in self.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<P,Poly> MulAssign for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_ref(&rhs);
+    }
+}
+
+impl<P,Poly> DivAssign for Fpk<P,Poly> where P: Prime + ModulusU64, Poly: ExtensionModulus<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
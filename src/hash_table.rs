@@ -0,0 +1,529 @@
+// src/hash_table.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+use core::{fmt, mem, slice};
+
+use generic_array::{ArrayLength, GenericArray, GenericArrayIter};
+use generic_array::sequence::GenericSequence;
+
+use Prime;
+
+
+/// A 64-bit FNV-1a hasher, the default hasher of `StaticHashTable`.
+///
+/// It is fast for short keys and needs no allocation or randomness,
+/// but it offers no protection against keys chosen to collide.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> FnvHasher {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Builds `FnvHasher`s.
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+
+#[derive(Clone)]
+enum Slot<K,V> {
+    Empty,
+    Deleted,
+    Occupied(K,V),
+    // An entry that `rehash` has yet to move.
+    Displaced(K,V),
+}
+
+
+/// A hash table with `N` buckets and no heap allocation.
+///
+/// Collisions are resolved by open addressing with double hashing.
+/// The hash of a key picks both the first bucket and the step between
+/// the buckets that are probed next. Because `N` is `Prime`, every
+/// step from 1 to `N-1` is coprime to `N`, so the probe sequence of
+/// any key visits every bucket and an insertion only fails when the
+/// table is full.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U13;
+/// # use typenum_prime::StaticHashTable;
+/// # fn main() {
+/// let mut table = StaticHashTable::<&str, u32, U13>::new();
+/// table.insert("two", 2).unwrap();
+/// table.insert("three", 3).unwrap();
+/// assert_eq!(table.get("two"), Some(&2));
+/// assert_eq!(table.remove("two"), Some(2));
+/// assert_eq!(table.get("two"), None);
+/// assert_eq!(table.len(), 1);
+/// # }
+/// ```
+///
+/// The number of buckets must be prime.
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U12;
+/// # use typenum_prime::StaticHashTable;
+/// # fn main() {
+/// let table = StaticHashTable::<u32, u32, U12>::new();
+/// # }
+/// ```
+pub struct StaticHashTable<K,V,N,S = FnvBuildHasher>
+    where N: Prime + ArrayLength {
+    buckets: GenericArray<Slot<K,V>,N>,
+    len: usize,
+    deleted: usize,
+    hash_builder: S,
+}
+
+impl<K,V,N,S> StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength,
+          S: Default {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K,V,N,S> Default for StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength,
+          S: Default {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K,V,N,S> StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength {
+    /// Create an empty table that hashes keys with `hash_builder`.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        StaticHashTable {
+            buckets: GenericArray::generate(|_| Slot::Empty),
+            len: 0,
+            deleted: 0,
+            hash_builder,
+        }
+    }
+
+    /// The number of buckets, which is the most entries the table can
+    /// hold.
+    pub fn capacity(&self) -> usize {
+        N::USIZE
+    }
+
+    /// The number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove every entry.
+    pub fn clear(&mut self) {
+        for slot in self.buckets.iter_mut() {
+            *slot = Slot::Empty;
+        }
+        self.len = 0;
+        self.deleted = 0;
+    }
+
+    /// An iterator over the entries, in bucket order.
+    pub fn iter(&self) -> Iter<'_,K,V> {
+        Iter {
+            slots: self.buckets.iter(),
+            remaining: self.len,
+        }
+    }
+
+    /// An iterator over the entries, in bucket order, with mutable
+    /// references to the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_,K,V> {
+        IterMut {
+            slots: self.buckets.iter_mut(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<K,V,N,S> Clone for StaticHashTable<K,V,N,S>
+    where K: Clone,
+          V: Clone,
+          N: Prime + ArrayLength,
+          S: Clone {
+    fn clone(&self) -> Self {
+        StaticHashTable {
+            buckets: self.buckets.clone(),
+            len: self.len,
+            deleted: self.deleted,
+            hash_builder: self.hash_builder.clone(),
+        }
+    }
+}
+
+impl<K,V,N,S> StaticHashTable<K,V,N,S>
+    where K: Hash + Eq,
+          N: Prime + ArrayLength,
+          S: BuildHasher {
+    /// Insert an entry, returning the value previously stored under
+    /// `key`, if any. If the table is full, the entry is handed back
+    /// as an error.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K,V)> {
+        // Walk the probe sequence once, remembering the first bucket
+        // the entry could go in, until the key or an empty bucket
+        // shows up.
+        let mut vacant = None;
+        for i in self.probe(&key) {
+            match self.buckets[i] {
+                Slot::Occupied(ref k, ref mut v) if *k == key => {
+                    return Ok(Some(mem::replace(v, value)));
+                },
+                Slot::Occupied(..) | Slot::Displaced(..) => {},
+                Slot::Deleted => {
+                    vacant.get_or_insert(i);
+                },
+                Slot::Empty => {
+                    vacant.get_or_insert(i);
+                    break;
+                },
+            }
+        }
+        match vacant {
+            Some(i) => {
+                if let Slot::Deleted = mem::replace(&mut self.buckets[i], Slot::Occupied(key, value)) {
+                    self.deleted -= 1;
+                }
+                self.len += 1;
+                Ok(None)
+            },
+            None => Err((key, value)),
+        }
+    }
+
+    /// A reference to the value stored under `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized {
+        let i = self.find(key)?;
+        match self.buckets[i] {
+            Slot::Occupied(_, ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// A mutable reference to the value stored under `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized {
+        let i = self.find(key)?;
+        match self.buckets[i] {
+            Slot::Occupied(_, ref mut v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Whether the table has an entry for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized {
+        self.find(key).is_some()
+    }
+
+    /// Remove the entry for `key`, returning its value.
+    ///
+    /// The bucket is marked as deleted rather than emptied, so that
+    /// lookups probing past it keep going. Once marked buckets make up
+    /// more than a quarter of the table, the entries are rehashed in
+    /// place and the marks are dropped, so that a long run of
+    /// insertions and removals does not slow down lookups of absent
+    /// keys. Occupied buckets do not count, so that removing from a
+    /// nearly full table does not rehash it every time.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized {
+        let i = self.find(key)?;
+        let value = match mem::replace(&mut self.buckets[i], Slot::Deleted) {
+            Slot::Occupied(_, v) => v,
+            _ => unreachable!(),
+        };
+        self.len -= 1;
+        self.deleted += 1;
+        if 4 * self.deleted > N::USIZE {
+            self.rehash();
+        }
+        Some(value)
+    }
+
+    // Move every entry to the first bucket of its probe sequence that
+    // no other entry has claimed, and empty the deleted buckets.
+    fn rehash(&mut self) {
+        for slot in self.buckets.iter_mut() {
+            *slot = match mem::replace(slot, Slot::Empty) {
+                Slot::Occupied(k, v) => Slot::Displaced(k, v),
+                _ => Slot::Empty,
+            };
+        }
+        for i in 0..N::USIZE {
+            if !matches!(self.buckets[i], Slot::Displaced(..)) {
+                continue;
+            }
+            let mut slot = mem::replace(&mut self.buckets[i], Slot::Empty);
+            // Placing an entry can evict another that is still waiting
+            // to be moved, which is placed next.
+            while let Slot::Displaced(k, v) = slot {
+                let j = self.probe(&k)
+                    .find(|&j| !matches!(self.buckets[j], Slot::Occupied(..)))
+                    .expect("an entry is out of the table, so a bucket is free");
+                slot = mem::replace(&mut self.buckets[j], Slot::Occupied(k, v));
+            }
+        }
+        self.deleted = 0;
+    }
+
+    // The bucket holding key, if any.
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized {
+        for i in self.probe(key) {
+            match self.buckets[i] {
+                Slot::Occupied(ref k, _) if k.borrow() == key => return Some(i),
+                Slot::Empty => return None,
+                _ => {},
+            }
+        }
+        None
+    }
+
+    // The sequence of buckets to try for key. The low half of the hash
+    // picks the first bucket and the high half picks a step from 1 to
+    // N-1.
+    fn probe<Q>(&self, key: &Q) -> Probe
+        where Q: Hash + ?Sized {
+        let hash = self.hash_builder.hash_one(key);
+        let n = N::U64;
+        let start = (hash & 0xffff_ffff) % n;
+        let step = match n {
+            2 => 1,
+            _ => 1 + (hash >> 32) % (n - 1),
+        };
+        Probe {
+            next: start as usize,
+            step: step as usize,
+            n: n as usize,
+            remaining: n as usize,
+        }
+    }
+}
+
+impl<K,V,N,S> fmt::Debug for StaticHashTable<K,V,N,S>
+    where K: fmt::Debug,
+          V: fmt::Debug,
+          N: Prime + ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a,K,V,N,S> IntoIterator for &'a StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a,K,V>;
+
+    fn into_iter(self) -> Iter<'a,K,V> {
+        self.iter()
+    }
+}
+
+impl<'a,K,V,N,S> IntoIterator for &'a mut StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a,K,V>;
+
+    fn into_iter(self) -> IterMut<'a,K,V> {
+        self.iter_mut()
+    }
+}
+
+impl<K,V,N,S> IntoIterator for StaticHashTable<K,V,N,S>
+    where N: Prime + ArrayLength {
+    type Item = (K,V);
+    type IntoIter = IntoIter<K,V,N>;
+
+    /// An iterator that moves the entries out of the table, in bucket
+    /// order.
+    fn into_iter(self) -> IntoIter<K,V,N> {
+        IntoIter {
+            slots: self.buckets.into_iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+
+// The buckets visited by double hashing, each exactly once.
+struct Probe {
+    next: usize,
+    step: usize,
+    n: usize,
+    remaining: usize,
+}
+
+impl Iterator for Probe {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let i = self.next;
+        self.next = (self.next + self.step) % self.n;
+        Some(i)
+    }
+}
+
+
+/// An iterator over the entries of a `StaticHashTable`.
+pub struct Iter<'a,K: 'a,V: 'a> {
+    slots: slice::Iter<'a, Slot<K,V>>,
+    remaining: usize,
+}
+
+impl<'a,K,V> Iterator for Iter<'a,K,V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(ref k, ref v) = *slot {
+                self.remaining -= 1;
+                return Some((k, v));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a,K,V> ExactSizeIterator for Iter<'a,K,V> {}
+
+
+/// An iterator over the entries of a `StaticHashTable`, with mutable
+/// references to the values.
+pub struct IterMut<'a,K: 'a,V: 'a> {
+    slots: slice::IterMut<'a, Slot<K,V>>,
+    remaining: usize,
+}
+
+impl<'a,K,V> Iterator for IterMut<'a,K,V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(ref k, ref mut v) = *slot {
+                self.remaining -= 1;
+                return Some((k, v));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a,K,V> ExactSizeIterator for IterMut<'a,K,V> {}
+
+
+/// An iterator that moves the entries out of a `StaticHashTable`.
+pub struct IntoIter<K,V,N> where N: ArrayLength {
+    slots: GenericArrayIter<Slot<K,V>,N>,
+    remaining: usize,
+}
+
+impl<K,V,N> Iterator for IntoIter<K,V,N> where N: ArrayLength {
+    type Item = (K,V);
+
+    fn next(&mut self) -> Option<(K,V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(k, v) = slot {
+                self.remaining -= 1;
+                return Some((k, v));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K,V,N> ExactSizeIterator for IntoIter<K,V,N> where N: ArrayLength {}
+
+#[test]
+fn test_deleted_buckets_are_reclaimed() {
+    use typenum::consts::U31;
+    let mut table = StaticHashTable::<u64, u64, U31>::new();
+    for k in 0..1_000 {
+        table.insert(k, k).unwrap();
+        if k >= 20 {
+            table.remove(&(k - 20)).unwrap();
+        }
+        assert!(4 * table.deleted <= 31);
+        let deleted = table.buckets.iter().filter(|s| matches!(s, Slot::Deleted)).count();
+        assert_eq!(deleted, table.deleted);
+    }
+}
+
+#[test]
+fn test_remove_from_nearly_full_table() {
+    use typenum::consts::U31;
+    let mut table = StaticHashTable::<u64, u64, U31>::new();
+    for k in 0..30 {
+        table.insert(k, k).unwrap();
+    }
+    // The marks pile up until they are more than a quarter of the
+    // table, and only then is it rehashed.
+    for k in 0..7 {
+        table.remove(&k).unwrap();
+        assert_eq!(table.deleted, k as usize + 1);
+    }
+    table.remove(&7).unwrap();
+    assert_eq!(table.deleted, 0);
+    assert!(table.buckets.iter().all(|s| !matches!(s, Slot::Deleted)));
+    for k in 8..30 {
+        assert_eq!(table.get(&k), Some(&k));
+    }
+    assert_eq!(table.len(), 22);
+}
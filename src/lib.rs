@@ -31,8 +31,28 @@
 //!
 //! ```ignore
 //! pub struct StaticHashTable<K,V,N>
-//!     where N: Prime + ArrayLength<Option<(K,V)>> {
-//!     buckets: GenericArray<Option<(K,V)>,N>
+//!     where N: Prime + ArrayLength {
+//!     buckets: GenericArray<Slot<K,V>,N>,
+//!     ...
+//! }
+//! ```
+//!
+//! This table is provided as `StaticHashTable` with the
+//! `generic-array` feature.
+//!
+//! ## Large constants
+//!
+//! Trial division limits `Prime` to integers of about 20 bits. With
+//! the `macros` feature, `prime!` turns larger prime literals into
+//! `Certified` types, which carry a certificate of their primality.
+//! Bound them by `CertifiedPrime`, which checks the certificate, and
+//! take the integer from its `Value`.
+//!
+//! ```ignore
+//! type P = prime!(2_147_483_647);
+//!
+//! fn modulus<P: CertifiedPrime>() -> u64 {
+//!     P::Value::U64
 //! }
 //! ```
 
@@ -40,32 +60,123 @@
 #![warn(missing_docs)]
 
 #[cfg_attr(test, macro_use)] pub extern crate typenum;
+#[cfg(feature = "generic-array")] pub extern crate generic_array;
+#[cfg(feature = "macros")] extern crate typenum_prime_macros;
+extern crate typenum_prime_runtime as runtime;
 
-use typenum::marker_traits::{Bit, Unsigned};
-use typenum::consts::True;
+use core::ops::{Add, Div, Mul, Shl, Shr, Sub};
 
-use private::PrivateIsPrime;
+use typenum::bit::B1;
+use typenum::marker_traits::{Bit, Integer, NonZero, TypeArray, Unsigned};
+use typenum::array::{ATerm, TArr};
+use typenum::consts::{False, True, U1, U2};
+use typenum::int::{NInt, PInt, Z0};
+use typenum::operator_aliases::{Add1, Diff, Eq, Prod, Quot, Shleft, Shright, Sub1};
+use typenum::type_operators::IsEqual;
+#[cfg(feature = "const-generics")] use typenum::{Const, ToUInt, U};
+
+use private::{CoprimeToAll, DistinctFromAll, DistinctPrimes, FillPrimes, Gcd, GcdOut,
+              GreaterThanOne, IsFalseFor, IsOne, IsOneOut, IsTrueFor, Magnitude, MagnitudeOut,
+              ModPowOut, ModulusU64, PrimeAtLeast, PrimeAtLeastOut, PrivateBarrett, PrivateCertificate,
+              PrivateCrt, PrivateCrtBasis, PrivateIsGaussianPrime, PrivateIsIrreducibleFp,
+              PrivateIsIrreducibleGf2, PrivateIsPrime, PrivateIsPrimitiveGf2, PrivateModPow,
+              PrivateMontgomery, PrivatePrimesUpTo, PrivatePrimitiveRoot, PrivateTwinOf, Reduce,
+              ReduceOut, SameResidue};
 
 
 #[doc(hidden)]
 pub mod private;
 
+pub use runtime::{is_prime_u64, is_prime_u128};
+
+mod fp;
+pub use fp::Fp;
+
+mod montgomery;
+pub use montgomery::Montgomery;
+
+mod barrett;
+pub use barrett::Barrett;
+
+mod prime_factor;
+pub use prime_factor::PrimeFactorMap;
+
+mod certified;
+pub use certified::Certified;
+
+#[cfg(feature = "generic-array")] mod hash_table;
+#[cfg(feature = "generic-array")]
+pub use hash_table::{FnvBuildHasher, FnvHasher, IntoIter, Iter, IterMut, StaticHashTable};
+
+#[cfg(feature = "generic-array")] mod fpk;
+#[cfg(feature = "generic-array")] pub use fpk::{ExtensionModulus, Fpk};
+
+#[cfg(feature = "generic-array")] mod ntt;
+#[cfg(feature = "generic-array")] pub use ntt::{inverse_ntt, ntt};
+
+#[cfg(feature = "generic-array")] mod rader;
+#[cfg(feature = "generic-array")] pub use rader::{rader_dft, rader_dft_with};
 
 // Test all integers from 0 through 1024, inclusive.
-#[cfg(test)] pub mod test_small_constants;
+#[cfg(test)] mod test_small_constants;
 
 
 /// **Type operator** for primality testing.
 ///
-/// This trait is implemented for all unsignd integers from the
-/// `typenum` crate.
+/// This trait is implemented for all unsigned integers from the
+/// `typenum` crate. See `IsPrimeAbs` for signed integers.
 pub trait IsPrime: Unsigned {
     /// A boolean indicating the result of the primality test.
     type Output: Bit;
+
+    // The smallest prime divisor of a composite Self, and Self
+    // otherwise, which the error message of an unsatisfied Prime bound
+    // names. It is here rather than in a supertrait so that a bound on
+    // IsPrime is enough to prove Prime.
+    #[doc(hidden)]
+    type Witness;
 }
 
 impl<N> IsPrime for N where N: Unsigned + PrivateIsPrime {
     type Output = <N as PrivateIsPrime>::Output;
+    type Witness = <N as PrivateIsPrime>::Witness;
+}
+
+
+/// **Type operator** for primality testing of the absolute value.
+///
+/// This trait is implemented for all signed integers from the
+/// `typenum` crate. An integer is prime when its magnitude is, so that
+/// both `P7` and `N7` are prime and `Z0` is not.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Bit;
+/// # use typenum_prime::IsPrimeAbs;
+/// # fn main() {
+/// assert!(<P7 as IsPrimeAbs>::Output::to_bool());
+/// assert!(<N7 as IsPrimeAbs>::Output::to_bool());
+/// assert!(!<N9 as IsPrimeAbs>::Output::to_bool());
+/// assert!(!<Z0 as IsPrimeAbs>::Output::to_bool());
+/// # }
+/// ```
+pub trait IsPrimeAbs: Integer {
+    /// A boolean indicating the result of the primality test.
+    type Output: Bit;
+}
+
+impl IsPrimeAbs for Z0 {
+    type Output = False;
+}
+
+impl<U> IsPrimeAbs for PInt<U> where U: Unsigned + NonZero + IsPrime {
+    type Output = <U as IsPrime>::Output;
+}
+
+impl<U> IsPrimeAbs for NInt<U> where U: Unsigned + NonZero + IsPrime {
+    type Output = <U as IsPrime>::Output;
 }
 
 
@@ -74,6 +185,1186 @@ impl<N> IsPrime for N where N: Unsigned + PrivateIsPrime {
 /// This trait is automatically implemented for unsigned integers from
 /// the `typenum` crate that are prime. It is not defined for 0, 1,
 /// and composite integers.
+///
+/// When the bound is not satisfied, the compiler error names the
+/// smallest prime divisor of a composite integer.
+///
+/// ```compile_fail,E0277
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U91;
+/// # use typenum_prime::Prime;
+/// fn buckets<N: Prime>() {}
+/// # fn main() {
+/// buckets::<U91>(); // error names `U7` as a divisor of `U91`
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a prime `typenum` integer"
+)]
 pub trait Prime: Unsigned {}
 
-impl<N> Prime for N where N: Unsigned + IsPrime<Output=True> {}
+impl<N> Prime for N
+    where N: Unsigned + IsPrime,
+          <N as IsPrime>::Output: IsTrueFor<N, <N as IsPrime>::Witness> {}
+
+
+/// **Trait** for primes that are `Prime` integers or carry a certificate
+///
+/// `Prime` checks primality by trial division, which is impractical
+/// beyond about 20 bits. The `prime!` macro instead writes larger
+/// primes as `Certified<N,C>`, where `C` is a certificate of the
+/// primality of `N`, and checking it takes a few hundred
+/// multiplications modulo `N`. `CertifiedPrime` is implemented for
+/// every `Prime` integer and for every `Certified` type whose
+/// certificate checks out, and `Value` is the prime.
+///
+/// `Fp`, `Montgomery` and `Barrett` take any `CertifiedPrime` that fits
+/// in a `u64` as their modulus, so these large primes are what they
+/// are most useful with.
+///
+/// The check still costs compile time: about a second for a 31-bit
+/// prime and half a minute for a 61-bit one. Primes near 64 bits also
+/// need `#![recursion_limit = "256"]`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U97;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::CertifiedPrime;
+/// fn modulus<P: CertifiedPrime>() -> u64 {
+///     P::Value::U64
+/// }
+/// # fn main() {
+/// assert_eq!(modulus::<U97>(), 97);
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a prime `typenum` integer or a prime with a valid certificate"
+)]
+pub trait CertifiedPrime {
+    /// The prime.
+    type Value: Unsigned;
+}
+
+impl<N> CertifiedPrime for N where N: Prime {
+    type Value = N;
+}
+
+impl<N,C> CertifiedPrime for Certified<N,C> where N: Unsigned, C: PrivateCertificate<N> {
+    type Value = N;
+}
+
+
+/// **Marker trait** for composite, unsigned integers; equivalent to `IsPrime<Output=False>` for integers greater than 1
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are composite, that is, 4 and up. It is
+/// not defined for 0, 1, and prime integers.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::Composite;
+/// fn tiles<N: Composite>() {}
+/// # fn main() {
+/// tiles::<U12>();
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composite `typenum` integer"
+)]
+pub trait Composite: Unsigned {}
+
+impl<N> Composite for N
+    where N: Unsigned + GreaterThanOne + PrivateIsPrime,
+          <N as PrivateIsPrime>::Output: IsFalseFor<N> {}
+
+
+/// **Marker trait** for unsigned integers that are not prime; equivalent to `IsPrime<Output=False>`
+///
+/// This trait is automatically implemented for 0, 1, and composite
+/// unsigned integers from the `typenum` crate.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is a prime `typenum` integer"
+)]
+pub trait NotPrime: Unsigned {}
+
+impl<N> NotPrime for N
+    where N: Unsigned + PrivateIsPrime,
+          <N as PrivateIsPrime>::Output: IsFalseFor<N> {}
+
+
+/// **Marker trait** for odd, prime, unsigned integers; equivalent to `Prime` for integers other than 2
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are prime and odd. It is the same as
+/// `PrimeCongruent<U1,U2>`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an odd prime `typenum` integer"
+)]
+pub trait OddPrime: Prime {}
+
+impl<N> OddPrime for N where N: PrimeCongruent<U1,U2> {}
+
+
+/// **Marker trait** for prime, unsigned integers congruent to `A` modulo `M`
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are prime and leave the same remainder as
+/// `A` when divided by `M`. `M` must not be zero.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::PrimeCongruent;
+/// // square roots modulo p are a single exponentiation
+/// fn simple_sqrt<P: PrimeCongruent<U3,U4>>() {}
+/// // p is a sum of two squares
+/// fn two_squares<P: PrimeCongruent<U1,U4>>() {}
+/// # fn main() {
+/// simple_sqrt::<U19>();
+/// two_squares::<U13>();
+/// # }
+/// ```
+pub trait PrimeCongruent<A,M>: Prime {}
+
+impl<P,A,M> PrimeCongruent<A,M> for P
+    where P: Prime + Reduce<M>,
+          A: Reduce<M>,
+          M: NonZero,
+          ReduceOut<P,M>: SameResidue<P,A,M,ReduceOut<A,M>> {}
+
+
+/// **Marker trait** for safe primes, that is, primes `p` such that `(p-1)/2` is also prime
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are safe primes.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::SafePrime;
+/// fn cyclic_group<P: SafePrime>() {}
+/// # fn main() {
+/// cyclic_group::<U23>();
+/// cyclic_group::<U107>();
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a safe prime `typenum` integer"
+)]
+pub trait SafePrime: OddPrime {}
+
+impl<P> SafePrime for P
+    where P: OddPrime + Shr<U1>,
+          Shright<P,U1>: Prime {}
+
+
+/// **Marker trait** for Sophie Germain primes, that is, primes `q` such that `2q+1` is also prime
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are Sophie Germain primes.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a Sophie Germain prime `typenum` integer"
+)]
+pub trait SophieGermainPrime: Prime {}
+
+impl<Q> SophieGermainPrime for Q
+    where Q: Prime + Shl<U1>,
+          Shleft<Q,U1>: Add<B1>,
+          Add1<Shleft<Q,U1>>: Prime {}
+
+
+/// **Type operator** for the safe prime partner of a Sophie Germain prime.
+///
+/// `Output` is `2q+1` for the Sophie Germain prime `q` that is `Self`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::SophieGermainPartner;
+/// # fn main() {
+/// assert_eq!(<U11 as SophieGermainPartner>::Output::to_u32(), 23);
+/// assert_eq!(<U53 as SophieGermainPartner>::Output::to_u32(), 107);
+/// # }
+/// ```
+pub trait SophieGermainPartner: SophieGermainPrime {
+    /// The safe prime `2*Self+1`.
+    type Output: SafePrime;
+}
+
+impl<Q> SophieGermainPartner for Q
+    where Q: SophieGermainPrime + Shl<U1>,
+          Shleft<Q,U1>: Add<B1>,
+          Add1<Shleft<Q,U1>>: SafePrime {
+    type Output = Add1<Shleft<Q,U1>>;
+}
+
+
+/// **Type operator** for the next prime.
+///
+/// `Output` is the smallest prime that is greater than `Self`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::NextPrime;
+/// # fn main() {
+/// assert_eq!(<U0 as NextPrime>::Output::to_u32(), 2);
+/// assert_eq!(<U7 as NextPrime>::Output::to_u32(), 11);
+/// assert_eq!(<U100 as NextPrime>::Output::to_u32(), 101);
+/// # }
+/// ```
+pub trait NextPrime: Unsigned {
+    /// The smallest prime greater than `Self`.
+    type Output: Prime;
+}
+
+impl<N> NextPrime for N
+    where N: Unsigned + Add<B1>,
+          Add1<N>: PrimeAtLeast,
+          PrimeAtLeastOut<Add1<N>>: Prime {
+    type Output = PrimeAtLeastOut<Add1<N>>;
+}
+
+
+/// **Type operator** for the gap after a prime.
+///
+/// `Output` is the distance from the prime `Self` to the next prime.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::PrimeGap;
+/// # fn main() {
+/// assert_eq!(<U2 as PrimeGap>::Output::to_u32(), 1);
+/// assert_eq!(<U23 as PrimeGap>::Output::to_u32(), 6);
+/// # }
+/// ```
+pub trait PrimeGap: Prime {
+    /// The next prime minus `Self`.
+    type Output: Unsigned;
+}
+
+impl<P> PrimeGap for P
+    where P: Prime + NextPrime,
+          <P as NextPrime>::Output: Sub<P>,
+          Diff<<P as NextPrime>::Output, P>: Unsigned {
+    type Output = Diff<<P as NextPrime>::Output, P>;
+}
+
+
+/// **Marker trait** for twin primes, that is, primes `p` such that `p-2` or `p+2` is also prime
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are twin primes.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::TwinPrime;
+/// fn double_hashing<P: TwinPrime>() {}
+/// # fn main() {
+/// double_hashing::<U73>();
+/// # }
+/// ```
+pub trait TwinPrime: Prime {}
+
+impl<P> TwinPrime for P where P: Prime + PrivateTwinOf {}
+
+
+/// **Type operator** for the other member of a twin prime pair.
+///
+/// `Output` is `p-2` if it is prime, and `p+2` otherwise, for the twin
+/// prime `p` that is `Self`. Preferring the smaller twin means that
+/// `Self` and `Output` can serve as the bucket count and the step
+/// modulus of double hashing.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::TwinOf;
+/// # fn main() {
+/// assert_eq!(<U73 as TwinOf>::Output::to_u32(), 71);
+/// assert_eq!(<U71 as TwinOf>::Output::to_u32(), 73);
+/// assert_eq!(<U5 as TwinOf>::Output::to_u32(), 3);
+/// # }
+/// ```
+pub trait TwinOf: TwinPrime {
+    /// The twin of `Self`.
+    type Output: Prime;
+}
+
+impl<P> TwinOf for P
+    where P: TwinPrime + PrivateTwinOf,
+          <P as PrivateTwinOf>::Output: Prime {
+    type Output = <P as PrivateTwinOf>::Output;
+}
+
+
+/// **Marker trait** for prime const generic integers; equivalent to `U<N>: Prime`
+///
+/// This trait is implemented for `Const<N>` when `N` is prime. It
+/// maps `N` to a `typenum` integer with `typenum::ToUInt`, so it is
+/// only defined for the values that `typenum::U` covers: 0 through
+/// 1024, the powers of 2 and of 10, the integers one less than a power
+/// of 2, and a few others such as 3600. Above 1024, the only primes
+/// among them are the Mersenne primes. 8191, 131071 and 524287 take
+/// seconds to check by trial division, but 2^31-1 and larger do not
+/// finish compiling in practice. Requires the `const-generics` feature.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::Const;
+/// # use typenum_prime::ConstPrime;
+/// fn buckets<const N: usize>() -> [u32; N] where Const<N>: ConstPrime {
+///     [0; N]
+/// }
+/// # fn main() {
+/// assert_eq!(buckets::<13>().len(), 13);
+/// assert_eq!(buckets::<8191>().len(), 8191);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::Const;
+/// # use typenum_prime::ConstPrime;
+/// fn buckets<const N: usize>() -> [u32; N] where Const<N>: ConstPrime {
+///     [0; N]
+/// }
+/// # fn main() {
+/// buckets::<91>(); // error: 91 is 7*13
+/// # }
+/// ```
+#[cfg(feature = "const-generics")]
+pub trait ConstPrime {}
+
+#[cfg(feature = "const-generics")]
+impl<const N: usize> ConstPrime for Const<N> where Const<N>: ToUInt, U<N>: Prime {}
+
+
+/// **Type operator** naming a divisor of a composite integer.
+///
+/// This trait is implemented for unsigned integers from the `typenum`
+/// crate that are composite. It is not defined for 0, 1, and prime
+/// integers. `Divisor` is the smallest prime divisor, which is the
+/// first one found by the trial division in `IsPrime`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::CompositeWitness;
+/// # fn main() {
+/// assert_eq!(<U91 as CompositeWitness>::Divisor::to_u32(), 7);
+/// # }
+/// ```
+pub trait CompositeWitness: Composite {
+    /// The smallest prime divisor of `Self`.
+    type Divisor: Unsigned;
+}
+
+impl<N> CompositeWitness for N
+    where N: Composite + PrivateIsPrime,
+          <N as PrivateIsPrime>::Witness: Unsigned {
+    type Divisor = <N as PrivateIsPrime>::Witness;
+}
+
+
+/// **Marker trait** for semiprimes, that is, products of exactly two primes
+///
+/// This trait is automatically implemented for unsigned integers from
+/// the `typenum` crate that are the product of two, not necessarily
+/// distinct, primes.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::Semiprime;
+/// fn grid<N: Semiprime>() {}
+/// # fn main() {
+/// grid::<U15>();
+/// grid::<U49>();
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a semiprime `typenum` integer"
+)]
+pub trait Semiprime: Composite {}
+
+impl<N> Semiprime for N
+    where N: Composite + PrivateIsPrime + Div<<N as PrivateIsPrime>::Witness>,
+          Quot<N, <N as PrivateIsPrime>::Witness>: Prime {}
+
+
+/// **Type operator** for the modulus of a toy RSA key.
+///
+/// `Self` and `Q` are the two distinct primes of the key. `Modulus` is
+/// their product N, and `Lambda` is the Carmichael function of N, that
+/// is, the least common multiple of `Self-1` and `Q-1`. A public
+/// exponent e is valid when it is `Coprime` with `Lambda`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::RsaModulus;
+/// # fn main() {
+/// assert_eq!(<U61 as RsaModulus<U53>>::Modulus::to_u32(), 3233);
+/// assert_eq!(<U61 as RsaModulus<U53>>::Lambda::to_u32(), 780);
+/// # }
+/// ```
+///
+/// The two primes must differ.
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U61;
+/// # use typenum_prime::RsaModulus;
+/// fn key<P: RsaModulus<Q>, Q>() {}
+/// # fn main() {
+/// key::<U61, U61>(); // error: `U61` and `U61` are the same prime
+/// # }
+/// ```
+pub trait RsaModulus<Q>: Prime {
+    /// The product of `Self` and `Q`.
+    type Modulus: Semiprime;
+    /// The Carmichael function of `Modulus`.
+    type Lambda: Unsigned;
+}
+
+impl<P,Q> RsaModulus<Q> for P
+    where P: Prime + IsEqual<Q> + Mul<Q> + Sub<B1>,
+          Q: Prime + Sub<B1>,
+          Eq<P,Q>: DistinctPrimes<P,Q>,
+          Prod<P,Q>: Semiprime,
+          Sub1<P>: Gcd<Sub1<Q>> + Mul<Sub1<Q>>,
+          Prod<Sub1<P>,Sub1<Q>>: Div<GcdOut<Sub1<P>,Sub1<Q>>>,
+          Quot<Prod<Sub1<P>,Sub1<Q>>, GcdOut<Sub1<P>,Sub1<Q>>>: Unsigned {
+    type Modulus = Prod<P,Q>;
+    type Lambda = Quot<Prod<Sub1<P>,Sub1<Q>>, GcdOut<Sub1<P>,Sub1<Q>>>;
+}
+
+
+/// **Type operator** for modular exponentiation.
+///
+/// `Output` is `Self` raised to the power `E`, reduced modulo `M`.
+/// `M` must not be zero.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::ModPow;
+/// # fn main() {
+/// assert_eq!(<U3 as ModPow<U4,U17>>::Output::to_u32(), 13);
+/// # }
+/// ```
+pub trait ModPow<E,M>: Unsigned {
+    /// `Self^E % M`
+    type Output: Unsigned;
+}
+
+impl<B,E,M> ModPow<E,M> for B
+    where B: Unsigned + PrivateModPow<E,M>,
+          M: NonZero,
+          ModPowOut<B,E,M>: Unsigned {
+    type Output = ModPowOut<B,E,M>;
+}
+
+
+/// **Type operator** for the smallest primitive root of a prime.
+///
+/// `Output` is the smallest positive integer that generates the
+/// multiplicative group of integers modulo `Self`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::PrimitiveRoot;
+/// # fn main() {
+/// assert_eq!(<U17 as PrimitiveRoot>::Output::to_u32(), 3);
+/// assert_eq!(<U41 as PrimitiveRoot>::Output::to_u32(), 6);
+/// # }
+/// ```
+pub trait PrimitiveRoot: Prime {
+    /// The smallest primitive root modulo `Self`.
+    type Output: Unsigned;
+}
+
+impl<P> PrimitiveRoot for P
+    where P: Prime + PrivatePrimitiveRoot,
+          <P as PrivatePrimitiveRoot>::Output: Unsigned {
+    type Output = <P as PrivatePrimitiveRoot>::Output;
+}
+
+
+/// **Marker trait** for primes that support a number-theoretic transform of length `2^K`
+///
+/// This trait is automatically implemented for primes `p` from the
+/// `typenum` crate such that `2^K` divides `p-1`, which is exactly
+/// when there is a primitive `2^K`-th root of unity modulo `p`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::NttPrime;
+/// fn transform<P: NttPrime<K>, K>() {}
+/// # fn main() {
+/// transform::<U17, U4>(); // 16 divides 17-1
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::NttPrime;
+/// fn transform<P: NttPrime<K>, K>() {}
+/// # fn main() {
+/// transform::<U17, U5>(); // error: 32 does not divide 17-1
+/// # }
+/// ```
+pub trait NttPrime<K>: Prime {}
+
+impl<P,K> NttPrime<K> for P
+    where U1: Shl<K>,
+          P: PrimeCongruent<U1, Shleft<U1,K>> {}
+
+
+/// **Type operator** for primitive roots of unity of order `2^K`.
+///
+/// `Output` is a primitive `2^K`-th root of unity modulo the prime
+/// `Self`, computed from its `PrimitiveRoot` `g` as
+/// `g^((p-1)/2^K) mod p`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::RootOfUnity;
+/// # fn main() {
+/// assert_eq!(<U17 as RootOfUnity<U3>>::Output::to_u32(), 9);
+/// assert_eq!(<U17 as RootOfUnity<U1>>::Output::to_u32(), 16);
+/// # }
+/// ```
+pub trait RootOfUnity<K>: NttPrime<K> {
+    /// A primitive `2^K`-th root of unity modulo `Self`.
+    type Output: Unsigned;
+}
+
+impl<P,K> RootOfUnity<K> for P
+    where P: NttPrime<K> + PrimitiveRoot + Sub<B1>,
+          U1: Shl<K>,
+          Sub1<P>: Div<Shleft<U1,K>>,
+          <P as PrimitiveRoot>::Output: ModPow<Quot<Sub1<P>, Shleft<U1,K>>, P> {
+    type Output = <<P as PrimitiveRoot>::Output as ModPow<Quot<Sub1<P>, Shleft<U1,K>>, P>>::Output;
+}
+
+
+/// **Type operator** for the constants of Montgomery multiplication.
+///
+/// With R = 2^64, `R` is R mod `Self`, `R2` is R² mod `Self`, and
+/// `NegInv` is -1/`Self` mod R. These are the constants that
+/// `Montgomery` needs to convert to and from Montgomery form and to
+/// reduce products.
+///
+/// This trait is implemented for every odd `CertifiedPrime`, so for
+/// the primes written with `prime!` as well as for `Prime` integers.
+/// Computing the constants for primes near 64 bits needs
+/// `#![recursion_limit = "256"]`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::MontgomeryConstants;
+/// # fn main() {
+/// assert_eq!(<U97 as MontgomeryConstants>::R::to_u64(), 61);
+/// assert_eq!(<U97 as MontgomeryConstants>::R2::to_u64(), 35);
+/// assert_eq!(<U97 as MontgomeryConstants>::NegInv::to_u64().wrapping_mul(97), u64::MAX);
+/// # }
+/// ```
+pub trait MontgomeryConstants: CertifiedPrime + ModulusU64 {
+    /// 2^64 mod `Self`.
+    type R: Unsigned;
+    /// 2^128 mod `Self`.
+    type R2: Unsigned;
+    /// -1/`Self` mod 2^64.
+    type NegInv: Unsigned;
+}
+
+// PrivateMontgomery is only implemented for odd integers.
+impl<P> MontgomeryConstants for P
+    where P: CertifiedPrime,
+          <P as CertifiedPrime>::Value: PrivateMontgomery,
+          <<P as CertifiedPrime>::Value as PrivateMontgomery>::R: Unsigned,
+          <<P as CertifiedPrime>::Value as PrivateMontgomery>::R2: Unsigned,
+          <<P as CertifiedPrime>::Value as PrivateMontgomery>::NegInv: Unsigned {
+    type R = <<P as CertifiedPrime>::Value as PrivateMontgomery>::R;
+    type R2 = <<P as CertifiedPrime>::Value as PrivateMontgomery>::R2;
+    type NegInv = <<P as CertifiedPrime>::Value as PrivateMontgomery>::NegInv;
+}
+
+
+/// **Type operator** for the constant of Barrett reduction.
+///
+/// The constant μ is floor(2^128 / `Self`). It is wider than 64 bits,
+/// so it is split into the words `MuHigh` and `MuLow`, with
+/// μ = `MuHigh`·2^64 + `MuLow`.
+///
+/// This trait is implemented for every `CertifiedPrime`, so for the
+/// primes written with `prime!` as well as for `Prime` integers.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::BarrettConstants;
+/// # fn main() {
+/// let high = <U97 as BarrettConstants>::MuHigh::to_u64() as u128;
+/// let low = <U97 as BarrettConstants>::MuLow::to_u64() as u128;
+/// assert_eq!(high << 64 | low, u128::MAX / 97);
+/// # }
+/// ```
+pub trait BarrettConstants: CertifiedPrime + ModulusU64 {
+    /// The high 64 bits of floor(2^128 / `Self`).
+    type MuHigh: Unsigned;
+    /// The low 64 bits of floor(2^128 / `Self`).
+    type MuLow: Unsigned;
+}
+
+impl<P> BarrettConstants for P
+    where P: CertifiedPrime,
+          <P as CertifiedPrime>::Value: PrivateBarrett,
+          <<P as CertifiedPrime>::Value as PrivateBarrett>::MuHigh: Unsigned,
+          <<P as CertifiedPrime>::Value as PrivateBarrett>::MuLow: Unsigned {
+    type MuHigh = <<P as CertifiedPrime>::Value as PrivateBarrett>::MuHigh;
+    type MuLow = <<P as CertifiedPrime>::Value as PrivateBarrett>::MuLow;
+}
+
+
+/// Assert at compile time that a `typenum` integer is prime.
+///
+/// The assertion is an item, so it can be placed at the top of a
+/// module as well as inside a function body.
+///
+/// ```
+/// # #[macro_use] extern crate typenum_prime;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// assert_prime!(U127);
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum_prime;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// assert_prime!(U91); // error names `U7` as a divisor of `U91`
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_prime {
+    ($n:ty) => {
+        const _: () = $crate::private::assert_prime::<$n>();
+    };
+}
+
+
+/// Assert at compile time that a `typenum` integer is composite.
+///
+/// The assertion is an item, so it can be placed at the top of a
+/// module as well as inside a function body.
+///
+/// ```
+/// # #[macro_use] extern crate typenum_prime;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// assert_composite!(U91);
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum_prime;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// assert_composite!(U127); // error: `U127` is prime
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_composite {
+    ($n:ty) => {
+        const _: () = $crate::private::assert_composite::<$n>();
+    };
+}
+
+
+/// Expand a prime integer literal to a type that implements
+/// `CertifiedPrime`.
+///
+/// The primality of the literal is checked while the macro expands,
+/// with a deterministic Miller-Rabin test below about 3.3e24 and the
+/// Baillie-PSW test above, exactly as `is_prime_u128` does. A
+/// composite literal is a compile error that names a divisor when one
+/// is small enough to find quickly.
+///
+/// Primes below 1024 expand to their `typenum` integer, which is
+/// `Prime`. Larger primes expand to `Certified<N,C>`, where `N` is the
+/// `typenum` integer and `C` is a Pocklington certificate of its
+/// primality, which is checked again when the type is bound by
+/// `CertifiedPrime`. That check takes a few hundred modular
+/// multiplications rather than trial division up to the square root.
+/// Finding the certificate requires factoring `N-1`, and a prime
+/// whose `N-1` resists factoring is a compile error.
+///
+/// The check is still slow. It takes about a second for a 31-bit
+/// prime and half a minute for a 61-bit one, in every crate that
+/// bounds the type by `CertifiedPrime`. Primes near 64 bits also need
+/// `#![recursion_limit = "256"]`.
+///
+/// The type can be the modulus of `Fp`, `Montgomery` and `Barrett`.
+///
+/// ```
+/// # #[macro_use] extern crate typenum_prime;
+/// # extern crate typenum;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::{CertifiedPrime, Fp};
+/// type P = prime!(2_147_483_647);
+/// # fn main() {
+/// assert_eq!(<P as CertifiedPrime>::Value::U64, 2_147_483_647);
+/// assert_eq!(Fp::<P>::new(1 << 30) * Fp::new(2), Fp::ONE);
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! prime {
+    ($($literal:tt)*) => {
+        $crate::private::prime!($crate; $($literal)*)
+    };
+}
+
+
+/// **Type operator** for Gaussian primality testing.
+///
+/// `Self` and `B` are the real and imaginary parts of the Gaussian
+/// integer a+bi, as signed integers from the `typenum` crate. When
+/// both parts are nonzero, a+bi is prime if its norm a²+b² is a
+/// prime. When one part is zero, a+bi is prime if the absolute value
+/// of the other is a prime congruent to 3 modulo 4.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Bit;
+/// # use typenum_prime::IsGaussianPrime;
+/// # fn main() {
+/// assert!(<P1 as IsGaussianPrime<P1>>::Output::to_bool());
+/// assert!(<N2 as IsGaussianPrime<P1>>::Output::to_bool());
+/// assert!(<Z0 as IsGaussianPrime<N7>>::Output::to_bool());
+/// assert!(!<P5 as IsGaussianPrime<Z0>>::Output::to_bool()); // (2+i)(2-i)
+/// # }
+/// ```
+pub trait IsGaussianPrime<B>: Integer {
+    /// A boolean indicating whether `Self` + `B`i is a Gaussian prime.
+    type Output: Bit;
+}
+
+impl<A,B> IsGaussianPrime<B> for A
+    where A: Integer + Magnitude,
+          B: Integer + Magnitude,
+          MagnitudeOut<A>: PrivateIsGaussianPrime<MagnitudeOut<B>> {
+    type Output = <MagnitudeOut<A> as PrivateIsGaussianPrime<MagnitudeOut<B>>>::Output;
+}
+
+
+/// **Marker trait** for Gaussian primes; equivalent to `IsGaussianPrime<B,Output=True>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` + `{B}`i is not a Gaussian prime"
+)]
+pub trait GaussianPrime<B>: Integer {}
+
+impl<A,B> GaussianPrime<B> for A where A: Integer + IsGaussianPrime<B,Output=True> {}
+
+
+/// **Type operator** for irreducibility testing of polynomials over GF(2).
+///
+/// `Self` encodes a polynomial over GF(2) as an unsigned integer from
+/// the `typenum` crate, with bit k holding the coefficient of x^k. For
+/// instance, `U11` is x³+x+1. The polynomial is irreducible when it
+/// has positive degree and no factors of smaller positive degree.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Bit;
+/// # use typenum_prime::IsIrreducibleGf2;
+/// # fn main() {
+/// assert!(<U283 as IsIrreducibleGf2>::Output::to_bool()); // the AES polynomial
+/// assert!(!<U17 as IsIrreducibleGf2>::Output::to_bool()); // x^4+1 == (x+1)^4
+/// # }
+/// ```
+pub trait IsIrreducibleGf2: Unsigned {
+    /// A boolean indicating whether `Self` is irreducible.
+    type Output: Bit;
+}
+
+impl<P> IsIrreducibleGf2 for P where P: Unsigned + PrivateIsIrreducibleGf2 {
+    type Output = <P as PrivateIsIrreducibleGf2>::Output;
+}
+
+
+/// **Marker trait** for irreducible polynomials over GF(2); equivalent to `IsIrreducibleGf2<Output=True>`
+///
+/// This is the bound for the modulus of a GF(2^n) field type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an irreducible polynomial over GF(2)"
+)]
+pub trait Gf2Irreducible: Unsigned {}
+
+impl<P> Gf2Irreducible for P where P: Unsigned + IsIrreducibleGf2<Output=True> {}
+
+
+/// **Type operator** for primitivity testing of polynomials over GF(2).
+///
+/// `Self` encodes a polynomial as for `IsIrreducibleGf2`. A polynomial
+/// of degree n is primitive when it is irreducible and x has order
+/// 2^n-1 modulo it, which makes it the feedback polynomial of a
+/// maximal-length LFSR. Testing this factors 2^n-1 by trial division,
+/// which limits the practical degree.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Bit;
+/// # use typenum_prime::IsPrimitiveGf2;
+/// # fn main() {
+/// assert!(<U285 as IsPrimitiveGf2>::Output::to_bool());
+/// assert!(!<U283 as IsPrimitiveGf2>::Output::to_bool()); // x has order 51
+/// # }
+/// ```
+pub trait IsPrimitiveGf2: Unsigned {
+    /// A boolean indicating whether `Self` is primitive.
+    type Output: Bit;
+}
+
+impl<P> IsPrimitiveGf2 for P where P: Unsigned + PrivateIsPrimitiveGf2 {
+    type Output = <P as PrivateIsPrimitiveGf2>::Output;
+}
+
+
+/// **Marker trait** for primitive polynomials over GF(2); equivalent to `IsPrimitiveGf2<Output=True>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a primitive polynomial over GF(2)"
+)]
+pub trait Gf2Primitive: Gf2Irreducible {}
+
+impl<P> Gf2Primitive for P where P: Gf2Irreducible + IsPrimitiveGf2<Output=True> {}
+
+
+/// **Type operator** for irreducibility testing of polynomials over
+/// GF(`P`).
+///
+/// `Self` is a `typenum` type-level array of the coefficients of the
+/// polynomial, constant term first. For instance, `tarr![U1, U0, U1]`
+/// is x²+1. Coefficients are taken modulo `P`, and the polynomial need
+/// not be monic. As over GF(2), it is irreducible when it has positive
+/// degree and no factors of smaller positive degree.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Bit;
+/// # use typenum_prime::IsIrreducibleFp;
+/// # fn main() {
+/// assert!(<tarr![U1, U0, U1] as IsIrreducibleFp<U3>>::Output::to_bool());
+/// assert!(!<tarr![U1, U0, U1] as IsIrreducibleFp<U5>>::Output::to_bool()); // (x+2)(x+3)
+/// assert!(!<tarr![U1, U0, U0, U0, U1] as IsIrreducibleFp<U3>>::Output::to_bool()); // (x^2+x+2)(x^2+2x+2)
+/// # }
+/// ```
+pub trait IsIrreducibleFp<P>: TypeArray where P: Prime {
+    /// A boolean indicating whether `Self` is irreducible.
+    type Output: Bit;
+}
+
+impl<A,P> IsIrreducibleFp<P> for A where A: TypeArray + PrivateIsIrreducibleFp<P>, P: Prime {
+    type Output = <A as PrivateIsIrreducibleFp<P>>::Output;
+}
+
+
+/// **Marker trait** for irreducible polynomials over GF(`P`); equivalent to `IsIrreducibleFp<P, Output=True>`
+///
+/// This is the bound for the modulus of the `Fpk` extension field type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an irreducible polynomial over GF({P})"
+)]
+pub trait FpIrreducible<P>: IsIrreducibleFp<P> where P: Prime {}
+
+impl<A,P> FpIrreducible<P> for A where A: IsIrreducibleFp<P, Output=True>, P: Prime {}
+
+
+/// **Type operator** for coprimality testing.
+///
+/// Two unsigned integers are coprime when their greatest common
+/// divisor is 1. This trait is implemented for all pairs of unsigned
+/// integers from the `typenum` crate.
+pub trait IsCoprime<Rhs>: Unsigned {
+    /// A boolean indicating whether `Self` and `Rhs` are coprime.
+    type Output: Bit;
+}
+
+impl<A,B> IsCoprime<B> for A where A: Unsigned + Gcd<B>, GcdOut<A,B>: IsOne {
+    type Output = IsOneOut<GcdOut<A,B>>;
+}
+
+
+/// **Marker trait** for pairs of coprime, unsigned integers; equivalent to `IsCoprime<Rhs,Output=True>`
+pub trait Coprime<Rhs>: Unsigned {}
+
+impl<A,B> Coprime<B> for A where A: Unsigned + IsCoprime<B,Output=True> {}
+
+
+/// **Type operator** for the Chinese remainder theorem.
+///
+/// `Self` is a `typenum` type-level array of residues and `Moduli` is
+/// an array of moduli with the same length. The moduli must be
+/// pairwise `Coprime`. `Residue` is the unique integer in the range
+/// `0..Modulus` that is congruent to every residue modulo its
+/// corresponding modulus, and `Modulus` is the product of the moduli.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::Crt;
+/// # fn main() {
+/// type Residues = tarr![U2, U3, U2];
+/// type Moduli = tarr![U3, U5, U7];
+/// assert_eq!(<Residues as Crt<Moduli>>::Residue::to_u32(), 23);
+/// assert_eq!(<Residues as Crt<Moduli>>::Modulus::to_u32(), 105);
+/// # }
+/// ```
+pub trait Crt<Moduli>: TypeArray {
+    /// The combined residue.
+    type Residue: Unsigned;
+    /// The product of the moduli.
+    type Modulus: Unsigned;
+}
+
+impl<R,M> Crt<M> for R
+    where R: TypeArray + PrivateCrt<M>,
+          <R as PrivateCrt<M>>::Residue: Unsigned,
+          <R as PrivateCrt<M>>::Modulus: Unsigned {
+    type Residue = <R as PrivateCrt<M>>::Residue;
+    type Modulus = <R as PrivateCrt<M>>::Modulus;
+}
+
+
+/// **Type operator** for the basis of the Chinese remainder theorem.
+///
+/// `First` is the integer in the range `0..Self*Rhs` that is congruent
+/// to 1 modulo `Self` and to 0 modulo `Rhs`, and `Second` is congruent
+/// to 0 modulo `Self` and to 1 modulo `Rhs`. The solution of
+/// x ≡ a (mod `Self`), x ≡ b (mod `Rhs`) is then
+/// a·`First` + b·`Second` modulo `Self*Rhs`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum::marker_traits::Unsigned;
+/// # use typenum_prime::CrtBasis;
+/// # fn main() {
+/// assert_eq!(<U3 as CrtBasis<U5>>::First::to_u32(), 10);
+/// assert_eq!(<U3 as CrtBasis<U5>>::Second::to_u32(), 6);
+/// # }
+/// ```
+pub trait CrtBasis<Rhs>: Coprime<Rhs> {
+    /// The residue 1 modulo `Self` and 0 modulo `Rhs`.
+    type First: Unsigned;
+    /// The residue 0 modulo `Self` and 1 modulo `Rhs`.
+    type Second: Unsigned;
+}
+
+impl<A,B> CrtBasis<B> for A
+    where A: Coprime<B> + PrivateCrtBasis<B>,
+          <A as PrivateCrtBasis<B>>::First: Unsigned,
+          <A as PrivateCrtBasis<B>>::Second: Unsigned {
+    type First = <A as PrivateCrtBasis<B>>::First;
+    type Second = <A as PrivateCrtBasis<B>>::Second;
+}
+
+
+/// **Marker trait** for type-level arrays of primes
+///
+/// This trait is automatically implemented for `typenum` type-level
+/// arrays whose elements are all `Prime`. The empty array qualifies.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::AllPrime;
+/// fn multi_hash<Moduli: AllPrime>() {}
+/// # fn main() {
+/// multi_hash::<tarr![U31, U37, U41]>();
+/// # }
+/// ```
+pub trait AllPrime: TypeArray {}
+
+impl AllPrime for ATerm {}
+
+impl<V,A> AllPrime for TArr<V,A> where V: Prime, A: AllPrime {}
+
+
+/// **Marker trait** for type-level arrays of pairwise coprime integers
+///
+/// This trait is automatically implemented for `typenum` type-level
+/// arrays of unsigned integers in which every two elements are
+/// `Coprime`, as required of the moduli of `Crt` and of a residue
+/// number system.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::PairwiseCoprime;
+/// fn rns<Moduli: PairwiseCoprime>() {}
+/// # fn main() {
+/// rns::<tarr![U7, U8, U9]>();
+/// # }
+/// ```
+pub trait PairwiseCoprime: TypeArray {}
+
+impl PairwiseCoprime for ATerm {}
+
+impl<V,A> PairwiseCoprime for TArr<V,A> where V: CoprimeToAll<A>, A: PairwiseCoprime {}
+
+
+/// **Marker trait** for type-level arrays of distinct integers
+///
+/// This trait is automatically implemented for `typenum` type-level
+/// arrays of unsigned integers in which no element appears twice.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::{AllPrime, Distinct};
+/// fn moduli<L: AllPrime + Distinct>() {}
+/// # fn main() {
+/// moduli::<tarr![U2, U3, U5]>();
+/// # }
+/// ```
+pub trait Distinct: TypeArray {}
+
+impl Distinct for ATerm {}
+
+impl<V,A> Distinct for TArr<V,A> where V: DistinctFromAll<A>, A: Distinct {}
+
+
+/// **Type operator** for the primes up to a bound.
+///
+/// `Output` is the `typenum` type-level array of all the primes no
+/// greater than `Self`, smallest first. Use `PrimeArray` to turn it
+/// into a runtime array.
+///
+/// Recursing through an array with more than about 100 elements, for
+/// instance with `typenum::Len`, may need a higher recursion limit.
+///
+/// ```
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::*;
+/// # use typenum_prime::{PrimeArray, PrimesUpTo};
+/// # fn main() {
+/// assert_type_eq!(<U12 as PrimesUpTo>::Output, tarr![U2, U3, U5, U7, U11]);
+/// let table: [u64; 10] = <U30 as PrimesUpTo>::Output::to_array();
+/// assert_eq!(table, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// # }
+/// ```
+pub trait PrimesUpTo: Unsigned {
+    /// The primes no greater than `Self`.
+    type Output: PrimeArray;
+}
+
+impl<N> PrimesUpTo for N
+    where N: Unsigned + PrivatePrimesUpTo,
+          <N as PrivatePrimesUpTo>::Output: PrimeArray {
+    type Output = <N as PrivatePrimesUpTo>::Output;
+}
+
+
+/// **Trait** for exporting type-level arrays of primes to runtime.
+///
+/// This trait is automatically implemented for `typenum` type-level
+/// arrays whose elements are all `Prime`.
+pub trait PrimeArray: AllPrime + FillPrimes {
+    /// The number of primes in the array.
+    const LEN: usize;
+
+    /// Return the primes as an array.
+    ///
+    /// # Compile errors
+    ///
+    /// `K` must equal `LEN`. This is asserted when the function is
+    /// instantiated for a particular `K`, so a mismatch is reported by
+    /// `cargo build`, but not by `cargo check`.
+    ///
+    /// ```compile_fail
+    /// # extern crate typenum;
+    /// # extern crate typenum_prime;
+    /// # use typenum::consts::U12;
+    /// # use typenum_prime::{PrimeArray, PrimesUpTo};
+    /// # fn main() {
+    /// let table: [u64; 4] = <U12 as PrimesUpTo>::Output::to_array(); // error: there are 5 primes
+    /// # }
+    /// ```
+    fn to_array<const K: usize>() -> [u64; K] {
+        const { assert!(K == Self::LEN, "array length differs from the number of primes") };
+        let mut out = [0; K];
+        Self::fill(&mut out);
+        out
+    }
+}
+
+impl PrimeArray for ATerm {
+    const LEN: usize = 0;
+}
+
+impl<V,A> PrimeArray for TArr<V,A> where V: Prime, A: PrimeArray {
+    const LEN: usize = A::LEN + 1;
+}
@@ -0,0 +1,280 @@
+// src/montgomery.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use typenum::marker_traits::Unsigned;
+
+use {Fp, MontgomeryConstants};
+
+
+/// An element of the prime field of integers modulo `P`, in Montgomery
+/// form.
+///
+/// The element a is stored as a·R mod `P`, with R = 2^64, so that
+/// multiplication needs no division by `P`. The constants for the
+/// conversion and the reduction are computed from `P` by
+/// `MontgomeryConstants`.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::U97;
+/// # use typenum_prime::{Fp, Montgomery};
+/// # fn main() {
+/// type M = Montgomery<U97>;
+/// let a = M::new(12);
+/// let b = M::new(90);
+/// assert_eq!((a * b).value(), 13);
+/// assert_eq!((a + b).value(), 5);
+/// assert_eq!(a * a.inv().unwrap(), M::ONE);
+/// assert_eq!(a / a, M::ONE);
+/// const INV: Option<M> = M::new(12).inv();
+/// assert_eq!(INV.unwrap().value(), 89);
+/// assert_eq!(Fp::from(a * b), Fp::new(12) * Fp::new(90));
+/// # }
+/// ```
+pub struct Montgomery<P> where P: MontgomeryConstants {
+    // a*R mod P
+    repr: u64,
+    prime: PhantomData<P>,
+}
+
+impl<P> Montgomery<P> where P: MontgomeryConstants {
+    /// R mod `P`, where R = 2^64.
+    pub const R: u64 = <P as MontgomeryConstants>::R::U64;
+
+    /// R² mod `P`.
+    pub const R2: u64 = <P as MontgomeryConstants>::R2::U64;
+
+    /// -1/`P` mod R.
+    pub const NEG_INV: u64 = <P as MontgomeryConstants>::NegInv::U64;
+
+    /// The additive identity.
+    pub const ZERO: Self = Montgomery { repr: 0, prime: PhantomData };
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Montgomery { repr: Self::R, prime: PhantomData };
+
+    /// The element congruent to `value` modulo `P`.
+    pub const fn new(value: u64) -> Self {
+        Montgomery { repr: redc::<P>((value % P::MODULUS) as u128 * Self::R2 as u128), prime: PhantomData }
+    }
+
+    /// The representative of `self` in the range `0..P`.
+    pub const fn value(self) -> u64 {
+        redc::<P>(self.repr as u128)
+    }
+
+    /// Whether `self` is zero.
+    pub const fn is_zero(self) -> bool {
+        self.repr == 0
+    }
+
+    /// `self` raised to the power `exp`.
+    pub const fn pow(self, mut exp: u64) -> Self {
+        let mut base = self.repr;
+        let mut result = Self::R;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = redc::<P>(result as u128 * base as u128);
+            }
+            base = redc::<P>(base as u128 * base as u128);
+            exp >>= 1;
+        }
+        Montgomery { repr: result, prime: PhantomData }
+    }
+
+    /// The multiplicative inverse of `self`, or `None` if `self` is
+    /// zero.
+    ///
+    /// By Fermat's little theorem, the inverse of a nonzero `a` is
+    /// `a^(P-2)`.
+    pub const fn inv(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.pow(P::MODULUS - 2))
+        }
+    }
+}
+
+// Montgomery reduction: t/R mod P, for t < P*R.
+const fn redc<P>(t: u128) -> u64 where P: MontgomeryConstants {
+    let p = P::MODULUS;
+    let m = (t as u64).wrapping_mul(<P as MontgomeryConstants>::NegInv::U64);
+    // t + m*P is divisible by R, but may overflow when P is close to
+    // 2^64. The quotient is less than 2*P.
+    let (sum, carry) = t.overflowing_add(m as u128 * p as u128);
+    let quotient = (sum >> 64) as u64;
+    if carry || quotient >= p { quotient.wrapping_sub(p) } else { quotient }
+}
+
+impl<P> Clone for Montgomery<P> where P: MontgomeryConstants {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Montgomery<P> where P: MontgomeryConstants {}
+
+impl<P> PartialEq for Montgomery<P> where P: MontgomeryConstants {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<P> Eq for Montgomery<P> where P: MontgomeryConstants {}
+
+impl<P> Hash for Montgomery<P> where P: MontgomeryConstants {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state);
+    }
+}
+
+impl<P> Default for Montgomery<P> where P: MontgomeryConstants {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P> fmt::Debug for Montgomery<P> where P: MontgomeryConstants {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value(), P::MODULUS)
+    }
+}
+
+impl<P> fmt::Display for Montgomery<P> where P: MontgomeryConstants {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl<P> From<u64> for Montgomery<P> where P: MontgomeryConstants {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<P> From<Fp<P>> for Montgomery<P> where P: MontgomeryConstants {
+    fn from(value: Fp<P>) -> Self {
+        Self::new(value.value())
+    }
+}
+
+impl<P> From<Montgomery<P>> for Fp<P> where P: MontgomeryConstants {
+    fn from(value: Montgomery<P>) -> Self {
+        Fp::new(value.value())
+    }
+}
+
+impl<P> Add for Montgomery<P> where P: MontgomeryConstants {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // The sum of representatives is the representative of the sum.
+        let gap = P::MODULUS - rhs.repr;
+        let repr = if self.repr >= gap { self.repr - gap } else { self.repr + rhs.repr };
+        Montgomery { repr, prime: PhantomData }
+    }
+}
+
+impl<P> Sub for Montgomery<P> where P: MontgomeryConstants {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<P> Neg for Montgomery<P> where P: MontgomeryConstants {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.repr {
+            0 => self,
+            r => Montgomery { repr: P::MODULUS - r, prime: PhantomData },
+        }
+    }
+}
+
+impl<P> Mul for Montgomery<P> where P: MontgomeryConstants {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // (a*R)*(b*R)/R == a*b*R
+        Montgomery { repr: redc::<P>(self.repr as u128 * rhs.repr as u128), prime: PhantomData }
+    }
+}
+
+impl<P> Div for Montgomery<P> where P: MontgomeryConstants {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv().expect("attempt to divide by zero")
+    }
+}
+
+impl<P> AddAssign for Montgomery<P> where P: MontgomeryConstants {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<P> SubAssign for Montgomery<P> where P: MontgomeryConstants {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<P> MulAssign for Montgomery<P> where P: MontgomeryConstants {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<P> DivAssign for Montgomery<P> where P: MontgomeryConstants {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[test]
+fn test_agrees_with_fp() {
+    use typenum::consts::{U3, U97, U1021};
+    fn check<P: MontgomeryConstants>() {
+        let p = P::MODULUS;
+        for a in (0..p).step_by(1 + p as usize / 50) {
+            assert_eq!(Montgomery::<P>::new(a).value(), a);
+            for b in (0..p).step_by(1 + p as usize / 40) {
+                let (ma, mb) = (Montgomery::<P>::new(a), Montgomery::<P>::new(b));
+                let (fa, fb) = (Fp::<P>::new(a), Fp::<P>::new(b));
+                assert_eq!(Fp::from(ma * mb), fa * fb);
+                assert_eq!(Fp::from(ma + mb), fa + fb);
+                assert_eq!(Fp::from(ma - mb), fa - fb);
+                if b != 0 {
+                    assert_eq!(Fp::from(ma / mb), fa / fb);
+                }
+            }
+        }
+        assert_eq!(Montgomery::<P>::R as u128, (1u128 << 64) % p as u128);
+        assert_eq!(P::MODULUS.wrapping_mul(Montgomery::<P>::NEG_INV), u64::MAX);
+    }
+    check::<U3>();
+    check::<U97>();
+    check::<U1021>();
+}
@@ -0,0 +1,118 @@
+// src/ntt.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use generic_array::{ArrayLength, GenericArray};
+use typenum::marker_traits::{PowerOfTwo, Unsigned};
+use typenum::operator_aliases::Log2;
+use typenum::type_operators::Logarithm2;
+
+use {Fp, Prime, RootOfUnity};
+
+
+/// The number-theoretic transform of `values`, in place.
+///
+/// This is the discrete Fourier transform over GF(`P`): entry k
+/// becomes the sum of `values[j]·ω^(jk)`, where ω is the primitive
+/// `N`-th root of unity given by `RootOfUnity`. The length `N` must be
+/// a power of two, and `P` must be an `NttPrime` for it, so a length
+/// that the modulus does not support is a compile error.
+///
+/// The transform turns cyclic convolution into pointwise
+/// multiplication, so two polynomials whose product has degree less
+/// than `N` can be multiplied exactly:
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::{U8, U97};
+/// # use typenum_prime::generic_array::GenericArray;
+/// # use typenum_prime::generic_array::functional::FunctionalSequence;
+/// # use typenum_prime::generic_array::sequence::GenericSequence;
+/// # use typenum_prime::{inverse_ntt, ntt, Fp};
+/// # fn main() {
+/// let poly = |coeffs: &[u64]| GenericArray::<Fp<U97>, U8>::generate(|i| Fp::new(*coeffs.get(i).unwrap_or(&0)));
+/// let mut a = poly(&[1, 2, 3]);
+/// let mut b = poly(&[4, 5]);
+/// ntt(&mut a);
+/// ntt(&mut b);
+/// let mut product = a.zip(b, |x, y| x * y);
+/// inverse_ntt(&mut product);
+/// assert_eq!(product, poly(&[4, 13, 22, 15]));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::{U17, U32};
+/// # use typenum_prime::generic_array::GenericArray;
+/// # use typenum_prime::{ntt, Fp};
+/// # fn main() {
+/// let mut values = GenericArray::<Fp<U17>, U32>::default();
+/// ntt(&mut values); // error: 32 does not divide 17-1
+/// # }
+/// ```
+pub fn ntt<P,N>(values: &mut GenericArray<Fp<P>,N>)
+    where N: ArrayLength + PowerOfTwo + Logarithm2,
+          P: RootOfUnity<Log2<N>> {
+    transform(values, Fp::new(<P as RootOfUnity<Log2<N>>>::Output::U64));
+}
+
+/// The inverse of `ntt`, in place.
+///
+/// This is the transform with ω replaced by 1/ω, divided by `N`.
+pub fn inverse_ntt<P,N>(values: &mut GenericArray<Fp<P>,N>)
+    where N: ArrayLength + PowerOfTwo + Logarithm2,
+          P: RootOfUnity<Log2<N>> {
+    let root = Fp::new(<P as RootOfUnity<Log2<N>>>::Output::U64);
+    // A root of unity is nonzero, and N divides P-1, so both inverses
+    // exist.
+    transform(values, root.inv().unwrap());
+    let scale = Fp::new(N::U64).inv().unwrap();
+    for v in values.iter_mut() {
+        *v *= scale;
+    }
+}
+
+// The iterative radix-2 Cooley-Tukey transform with the primitive
+// n-th root of unity root, where n is the length of values, a power of
+// two. The input is put in bit-reversed order first, so the output is
+// in natural order.
+fn transform<P>(values: &mut [Fp<P>], root: Fp<P>) where P: Prime {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        // a primitive len-th root of unity
+        let step = root.pow((n / len) as u64);
+        for block in values.chunks_mut(len) {
+            let (low, high) = block.split_at_mut(len / 2);
+            let mut w = Fp::ONE;
+            for (u, v) in low.iter_mut().zip(high.iter_mut()) {
+                let t = *v * w;
+                *v = *u - t;
+                *u += t;
+                w *= step;
+            }
+        }
+        len <<= 1;
+    }
+}
@@ -0,0 +1,173 @@
+// src/prime_factor.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "generic-array")] use core::ops::Mul;
+
+#[cfg(feature = "generic-array")] use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "generic-array")] use generic_array::sequence::GenericSequence;
+use typenum::marker_traits::Unsigned;
+#[cfg(feature = "generic-array")] use typenum::operator_aliases::Prod;
+
+use CrtBasis;
+
+
+/// The index maps of the prime-factor (Good–Thomas) algorithm for an
+/// array of length `N1`·`N2`.
+///
+/// When `N1` and `N2` are `Coprime`, the discrete Fourier transform of
+/// length `N1`·`N2` splits into transforms of lengths `N1` and `N2`
+/// with no twiddle factors in between. Input n1, n2 is read from
+/// index (n1·`N2` + n2·`N1`) mod `N1`·`N2`, and output k1, k2 is
+/// written to the index that is k1 modulo `N1` and k2 modulo `N2`,
+/// which is k1·e1 + k2·e2 for the `CrtBasis` e1, e2. The basis is
+/// computed at compile time, and a pair of factors that are not
+/// coprime is a compile error.
+///
+/// With ω a primitive (`N1`·`N2`)-th root of unity, the inputs in an
+/// `N1`×`N2` grid are transformed along each row with ω^`N1` and along
+/// each column with ω^`N2`, and the grid holds the outputs at the
+/// same coordinates.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::{U3, U5};
+/// # use typenum_prime::PrimeFactorMap;
+/// # fn main() {
+/// let map = PrimeFactorMap::<U3, U5>::new();
+/// assert_eq!(PrimeFactorMap::<U3, U5>::LEN, 15);
+/// assert_eq!(map.input_index(2, 4), 7);
+/// assert_eq!(map.input_coords(7), (2, 4));
+/// assert_eq!(map.output_index(2, 4), 14);
+/// assert_eq!(map.output_coords(14), (2, 4));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::{U4, U6};
+/// # use typenum_prime::PrimeFactorMap;
+/// # fn main() {
+/// let map = PrimeFactorMap::<U4, U6>::new(); // error: 4 and 6 are not coprime
+/// # }
+/// ```
+pub struct PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {
+    factors: PhantomData<(N1,N2)>,
+}
+
+impl<N1,N2> PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {
+    /// The length of the array, `N1`·`N2`.
+    pub const LEN: usize = N1::USIZE * N2::USIZE;
+
+    // The inverses of N2 modulo N1 and of N1 modulo N2, which the
+    // basis elements are multiples of.
+    const INV2: usize = <N1 as CrtBasis<N2>>::First::USIZE / N2::USIZE;
+    const INV1: usize = <N1 as CrtBasis<N2>>::Second::USIZE / N1::USIZE;
+
+    /// Create the map.
+    pub const fn new() -> Self {
+        PrimeFactorMap { factors: PhantomData }
+    }
+
+    /// The index of input `n1`, `n2`, where `n1` < `N1` and `n2` < `N2`.
+    pub const fn input_index(self, n1: usize, n2: usize) -> usize {
+        (n1 * N2::USIZE + n2 * N1::USIZE) % Self::LEN
+    }
+
+    /// The input coordinates of `index`; the inverse of `input_index`.
+    pub const fn input_coords(self, index: usize) -> (usize, usize) {
+        (index % N1::USIZE * Self::INV2 % N1::USIZE,
+         index % N2::USIZE * Self::INV1 % N2::USIZE)
+    }
+
+    /// The index of output `k1`, `k2`, where `k1` < `N1` and `k2` < `N2`.
+    pub const fn output_index(self, k1: usize, k2: usize) -> usize {
+        (k1 * <N1 as CrtBasis<N2>>::First::USIZE
+         + k2 * <N1 as CrtBasis<N2>>::Second::USIZE) % Self::LEN
+    }
+
+    /// The output coordinates of `index`; the inverse of `output_index`.
+    pub const fn output_coords(self, index: usize) -> (usize, usize) {
+        (index % N1::USIZE, index % N2::USIZE)
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<N1,N2> PrimeFactorMap<N1,N2>
+    where N1: CrtBasis<N2> + Mul<N2>,
+          N2: Unsigned,
+          Prod<N1,N2>: ArrayLength {
+    /// The inputs in `values`, rearranged into an `N1`×`N2` grid in
+    /// row-major order.
+    pub fn gather_input<T: Copy>(self, values: &GenericArray<T,Prod<N1,N2>>)
+                                 -> GenericArray<T,Prod<N1,N2>> {
+        GenericArray::generate(|i| values[self.input_index(i / N2::USIZE, i % N2::USIZE)])
+    }
+
+    /// The outputs in the row-major `N1`×`N2` `grid`, rearranged into
+    /// their order in the transform; the inverse of `gather_input`
+    /// with the output maps.
+    pub fn scatter_output<T: Copy>(self, grid: &GenericArray<T,Prod<N1,N2>>)
+                                   -> GenericArray<T,Prod<N1,N2>> {
+        GenericArray::generate(|k| {
+            let (k1, k2) = self.output_coords(k);
+            grid[k1 * N2::USIZE + k2]
+        })
+    }
+}
+
+impl<N1,N2> Clone for PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N1,N2> Copy for PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {}
+
+impl<N1,N2> Default for PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N1,N2> fmt::Debug for PrimeFactorMap<N1,N2> where N1: CrtBasis<N2>, N2: Unsigned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PrimeFactorMap({}x{})", N1::USIZE, N2::USIZE)
+    }
+}
+
+#[test]
+fn test_maps_are_inverse_bijections() {
+    use typenum::consts::{U1, U3, U4, U5, U7, U9};
+    fn check<N1: CrtBasis<N2>, N2: Unsigned>() {
+        let map = PrimeFactorMap::<N1,N2>::new();
+        let mut seen = [(false, false); 64];
+        for a in 0..N1::USIZE {
+            for b in 0..N2::USIZE {
+                let (i, o) = (map.input_index(a, b), map.output_index(a, b));
+                assert_eq!(map.input_coords(i), (a, b));
+                assert_eq!(map.output_coords(o), (a, b));
+                assert!(!seen[i].0 && !seen[o].1);
+                seen[i].0 = true;
+                seen[o].1 = true;
+            }
+        }
+        assert!(seen[..PrimeFactorMap::<N1,N2>::LEN].iter().all(|&s| s == (true, true)));
+    }
+    check::<U3,U5>();
+    check::<U5,U3>();
+    check::<U4,U9>();
+    check::<U1,U7>();
+    check::<U7,U1>();
+}
@@ -0,0 +1,141 @@
+// src/private/barrett.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use typenum::bit::{B0, B1};
+use typenum::consts::{U0, U1, U64};
+use typenum::uint::{UInt, UTerm};
+
+use super::reduction::{PushBit, PushBitOut, TrySub, TrySubOut, Underflowed};
+
+
+// A type operator for long division by M of a dividend whose low bits
+// are all zero. Self is (Q,R), the quotient and remainder of the high
+// bits N, and the Output is the quotient and remainder of N*2^K.
+//
+// Bringing down one bit at a time would recurse once per bit, which is
+// too deep for 2^128. Instead the walk follows the bits of K, doing
+// the steps for K/2 twice, so the recursion depth only grows with the
+// number of bits in K.
+pub trait ShiftDiv<M,K> {
+    type Output;
+}
+
+// Alias for the result of ShiftDiv.
+pub type ShiftDivOut<S,M,K> = <S as ShiftDiv<M,K>>::Output;
+
+impl<Q,R,M> ShiftDiv<M,UTerm> for (Q,R) {
+    type Output = (Q,R);
+}
+
+impl<Q,R,M,Uk> ShiftDiv<M,UInt<Uk,B0>> for (Q,R)
+    where (Q,R): ShiftDiv<M,Uk>,
+          ShiftDivOut<(Q,R),M,Uk>: ShiftDiv<M,Uk> {
+    type Output = ShiftDivOut<ShiftDivOut<(Q,R),M,Uk>,M,Uk>;
+}
+
+impl<Q,R,M,Uk> ShiftDiv<M,UInt<Uk,B1>> for (Q,R)
+    where (Q,R): ShiftDiv<M,Uk>,
+          ShiftDivOut<(Q,R),M,Uk>: ShiftDiv<M,Uk>,
+          ShiftDivOut<ShiftDivOut<(Q,R),M,Uk>,M,Uk>: ShiftDivOnce<M> {
+    type Output = <ShiftDivOut<ShiftDivOut<(Q,R),M,Uk>,M,Uk> as ShiftDivOnce<M>>::Output;
+}
+
+
+// A type operator for one step of ShiftDiv: bring down a zero bit and
+// subtract M if it fits.
+pub trait ShiftDivOnce<M> {
+    type Output;
+}
+
+impl<Q,R,M> ShiftDivOnce<M> for (Q,R)
+    where R: PushBit<B0>,
+          PushBitOut<R,B0>: TrySub<M>,
+          TrySubOut<PushBitOut<R,B0>,M>: DivStep<Q, PushBitOut<R,B0>> {
+    type Output = <TrySubOut<PushBitOut<R,B0>,M> as DivStep<Q, PushBitOut<R,B0>>>::Output;
+}
+
+
+// A type operator for the end of a division step. Self is the partial
+// remainder T minus the divisor, or Underflowed if the divisor did
+// not fit, and Q is the quotient so far. The Output is the new
+// quotient and remainder.
+pub trait DivStep<Q,T> {
+    type Output;
+}
+
+impl<Q,T> DivStep<Q,T> for Underflowed
+    where Q: PushBit<B0> {
+    type Output = (PushBitOut<Q,B0>, T);
+}
+
+impl<Q,T> DivStep<Q,T> for UTerm
+    where Q: PushBit<B1> {
+    type Output = (PushBitOut<Q,B1>, UTerm);
+}
+
+impl<U,B,Q,T> DivStep<Q,T> for UInt<U,B>
+    where Q: PushBit<B1> {
+    type Output = (PushBitOut<Q,B1>, UInt<U,B>);
+}
+
+#[test]
+fn test_shift_div() {
+    use typenum::consts::*;
+    assert_type_eq!((U1, U1), ShiftDivOut<(U0,U1), U7, U3>);
+    assert_type_eq!((U0, U1), ShiftDivOut<(U0,U1), U7, U0>);
+    assert_type_eq!((U5, U1), ShiftDivOut<(U2,U4), U7, U1>);
+    assert_type_eq!((U146, U2), ShiftDivOut<(U0,U1), U7, U10>);
+}
+
+
+// A type operator for the Barrett reduction constant floor(2^128/Self),
+// split into its high and low 64-bit words.
+//
+// With 2^64 == q*Self + r, the high word is q, and the low word is
+// floor(r*2^64/Self), which is less than 2^64 because r < Self.
+pub trait PrivateBarrett {
+    type MuHigh;
+    type MuLow;
+}
+
+impl<U,B> PrivateBarrett for UInt<U,B>
+    where Self: BarrettWithShift<U64> {
+    type MuHigh = <Self as BarrettWithShift<U64>>::MuHigh;
+    type MuLow = <Self as BarrettWithShift<U64>>::MuLow;
+}
+
+
+// The constant of PrivateBarrett for 2^(2*K). Taking K as a parameter
+// keeps the compiler from expanding the division for a divisor that
+// is not yet known.
+pub trait BarrettWithShift<K> {
+    type MuHigh;
+    type MuLow;
+}
+
+impl<P,K,Q,R,Lo,R2> BarrettWithShift<K> for P
+    where (U0,U1): ShiftDiv<P,K, Output = (Q,R)>,
+          (U0,R): ShiftDiv<P,K, Output = (Lo,R2)> {
+    type MuHigh = Q;
+    type MuLow = Lo;
+}
+
+#[test]
+fn test_barrett() {
+    use typenum::consts::*;
+    use typenum::marker_traits::Unsigned;
+    fn mu<P: PrivateBarrett>() -> u128
+        where P::MuHigh: Unsigned, P::MuLow: Unsigned {
+        (P::MuHigh::U64 as u128) << 64 | P::MuLow::U64 as u128
+    }
+    assert_eq!(mu::<U2>(), 1 << 127);
+    assert_eq!(mu::<U97>(), u128::MAX / 97);
+    assert_eq!(mu::<U1021>(), u128::MAX / 1021);
+}
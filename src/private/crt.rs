@@ -0,0 +1,126 @@
+// src/private/crt.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, Mul, Sub};
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::B1;
+use typenum::consts::{U0, U1};
+use typenum::operator_aliases::{Diff, Prod, Sum};
+use typenum::uint::{UInt, UTerm};
+
+use Coprime;
+use super::euclid::{ModInverse, ModInverseOut};
+use super::reduction::{Reduce, ReduceOut};
+
+
+// (b-a)%m, offset by m so that the subtraction cannot underflow.
+type CrtDelta<A,B,M> = Diff<Sum<ReduceOut<B,M>,M>, ReduceOut<A,M>>;
+
+// The multiple of n that must be added to a to reach the solution.
+type CrtLift<A,N,B,M> = ReduceOut<Prod<CrtDelta<A,B,M>, ModInverseOut<N,M>>, M>;
+
+
+// A type operator. Self is a residue A modulo N, where A < N. The
+// Output is the unique X modulo N*M such that X == A (mod N) and
+// X == B (mod M). N and M must be coprime.
+//
+// The solution is X = A + N*(((B-A)*inv(N)) % M), where inv(N) is the
+// inverse of N modulo M.
+pub trait CrtCombine<N,B,M> {
+    type Output;
+}
+
+impl<A,N,B,M> CrtCombine<N,B,M> for A
+    where A: Reduce<M>,
+          B: Reduce<M>,
+          ReduceOut<B,M>: Add<M>,
+          Sum<ReduceOut<B,M>,M>: Sub<ReduceOut<A,M>>,
+          N: ModInverse<M>,
+          CrtDelta<A,B,M>: Mul<ModInverseOut<N,M>>,
+          Prod<CrtDelta<A,B,M>, ModInverseOut<N,M>>: Reduce<M>,
+          N: Mul<CrtLift<A,N,B,M>>,
+          A: Add<Prod<N, CrtLift<A,N,B,M>>> {
+    type Output = Sum<A, Prod<N, CrtLift<A,N,B,M>>>;
+}
+
+// Alias for the result of CrtCombine.
+type CrtCombineOut<A,N,B,M> = <A as CrtCombine<N,B,M>>::Output;
+
+
+// A type operator for the Chinese remainder theorem. Self is a list
+// of residues and Moduli is a list of moduli with the same length.
+// The congruences are combined one at a time, starting from the end
+// of the lists, and each modulus must be coprime to the product of
+// the moduli after it. This is equivalent to requiring the moduli to
+// be pairwise coprime.
+pub trait PrivateCrt<Moduli> {
+    type Residue;
+    type Modulus;
+}
+
+// The empty system of congruences is solved by every integer.
+impl PrivateCrt<ATerm> for ATerm {
+    type Residue = UTerm;
+    type Modulus = UInt<UTerm,B1>;
+}
+
+impl<R,Rs,M,Ms> PrivateCrt<TArr<M,Ms>> for TArr<R,Rs>
+    where Rs: PrivateCrt<Ms>,
+          <Rs as PrivateCrt<Ms>>::Modulus: Coprime<M>,
+          <Rs as PrivateCrt<Ms>>::Modulus: Mul<M>,
+          <Rs as PrivateCrt<Ms>>::Residue: CrtCombine<<Rs as PrivateCrt<Ms>>::Modulus, R, M> {
+    type Residue = CrtCombineOut<<Rs as PrivateCrt<Ms>>::Residue,
+                                 <Rs as PrivateCrt<Ms>>::Modulus,
+                                 R, M>;
+    type Modulus = Prod<<Rs as PrivateCrt<Ms>>::Modulus, M>;
+}
+
+#[test]
+fn test_crt() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, <tarr![] as PrivateCrt<tarr![]>>::Residue);
+    assert_type_eq!(U1, <tarr![] as PrivateCrt<tarr![]>>::Modulus);
+    assert_type_eq!(U3, <tarr![U10] as PrivateCrt<tarr![U7]>>::Residue);
+    assert_type_eq!(U7, <tarr![U10] as PrivateCrt<tarr![U7]>>::Modulus);
+    assert_type_eq!(U5, <tarr![U1,U0] as PrivateCrt<tarr![U4,U5]>>::Residue);
+    assert_type_eq!(U20, <tarr![U1,U0] as PrivateCrt<tarr![U4,U5]>>::Modulus);
+    assert_type_eq!(U23, <tarr![U2,U3,U2] as PrivateCrt<tarr![U3,U5,U7]>>::Residue);
+    assert_type_eq!(U105, <tarr![U2,U3,U2] as PrivateCrt<tarr![U3,U5,U7]>>::Modulus);
+    assert_type_eq!(U0, <tarr![U0,U0] as PrivateCrt<tarr![U1,U9]>>::Residue);
+}
+
+
+// A type operator for the basis of the Chinese remainder theorem for
+// the coprime moduli Self and M. First is congruent to 1 modulo Self
+// and to 0 modulo M, and Second the other way around, so that
+// a*First + b*Second is congruent to a modulo Self and to b modulo M.
+pub trait PrivateCrtBasis<M> {
+    type First;
+    type Second;
+}
+
+impl<N,M> PrivateCrtBasis<M> for N
+    where TArr<U1,TArr<U0,ATerm>>: PrivateCrt<TArr<N,TArr<M,ATerm>>>,
+          TArr<U0,TArr<U1,ATerm>>: PrivateCrt<TArr<N,TArr<M,ATerm>>> {
+    type First = <TArr<U1,TArr<U0,ATerm>> as PrivateCrt<TArr<N,TArr<M,ATerm>>>>::Residue;
+    type Second = <TArr<U0,TArr<U1,ATerm>> as PrivateCrt<TArr<N,TArr<M,ATerm>>>>::Residue;
+}
+
+#[test]
+fn test_crt_basis() {
+    use typenum::consts::*;
+    assert_type_eq!(U10, <U3 as PrivateCrtBasis<U5>>::First);
+    assert_type_eq!(U6, <U3 as PrivateCrtBasis<U5>>::Second);
+    assert_type_eq!(U0, <U1 as PrivateCrtBasis<U7>>::First);
+    assert_type_eq!(U1, <U1 as PrivateCrtBasis<U7>>::Second);
+    assert_type_eq!(U81, <U16 as PrivateCrtBasis<U9>>::First);
+    assert_type_eq!(U64, <U16 as PrivateCrtBasis<U9>>::Second);
+}
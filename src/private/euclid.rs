@@ -0,0 +1,114 @@
+// src/private/euclid.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Div, Mul, Sub};
+
+use typenum::bit::B1;
+use typenum::operator_aliases::{Diff, Prod, Quot, Sub1};
+use typenum::uint::{UInt, UTerm};
+
+use super::reduction::{Reduce, ReduceOut};
+
+
+// A type operator for the greatest common divisor of Self and RHS,
+// computed with Euclid's algorithm.
+pub trait Gcd<RHS> {
+    type Output;
+}
+
+// gcd(a,0) == a
+impl<A> Gcd<UTerm> for A {
+    type Output = A;
+}
+
+// gcd(a,b) == gcd(b,a%b)
+impl<A,Ub,Bb> Gcd<UInt<Ub,Bb>> for A
+    where A: Reduce<UInt<Ub,Bb>>,
+          UInt<Ub,Bb>: Gcd<ReduceOut<A,UInt<Ub,Bb>>> {
+    type Output = GcdOut<UInt<Ub,Bb>, ReduceOut<A,UInt<Ub,Bb>>>;
+}
+
+// Alias for the result of Gcd.
+pub type GcdOut<A,B> = <A as Gcd<B>>::Output;
+
+#[test]
+fn test_gcd() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, GcdOut<U0,U0>);
+    assert_type_eq!(U5, GcdOut<U5,U0>);
+    assert_type_eq!(U5, GcdOut<U0,U5>);
+    assert_type_eq!(U1, GcdOut<U8,U15>);
+    assert_type_eq!(U4, GcdOut<U12,U8>);
+    assert_type_eq!(U4, GcdOut<U8,U12>);
+    assert_type_eq!(U7, GcdOut<U91,U70>);
+}
+
+
+// A type operator for the multiplicative inverse of Self modulo M,
+// where Self is already reduced modulo M. It is only implemented
+// when Self and M are coprime.
+//
+// For 1 < a < m, the inverse is computed from the inverse of m
+// modulo a (a smaller problem): if m*y == 1 + k*a, then
+// a*(m-k) == 1 (mod m), and k == (m*y-1)/a.
+pub trait ReducedModInverse<M> {
+    type Output;
+}
+
+// Everything is congruent modulo 1.
+impl ReducedModInverse<UInt<UTerm,B1>> for UTerm {
+    type Output = UTerm;
+}
+
+// 1 is its own inverse.
+impl<M> ReducedModInverse<M> for UInt<UTerm,B1> {
+    type Output = UInt<UTerm,B1>;
+}
+
+impl<U,Ba,Bb,M> ReducedModInverse<M> for UInt<UInt<U,Ba>,Bb>
+    where M: ModInverse<Self>,
+          M: Mul<ModInverseOut<M,Self>>,
+          Prod<M,ModInverseOut<M,Self>>: Sub<B1>,
+          Sub1<Prod<M,ModInverseOut<M,Self>>>: Div<Self>,
+          M: Sub<Quot<Sub1<Prod<M,ModInverseOut<M,Self>>>, Self>> {
+    type Output = Diff<M, Quot<Sub1<Prod<M,ModInverseOut<M,Self>>>, Self>>;
+}
+
+
+// A type operator for the multiplicative inverse of Self modulo M,
+// in the range 0 <= Output < M. It is only implemented when Self and
+// M are coprime.
+pub trait ModInverse<M> {
+    type Output;
+}
+
+impl<A,M> ModInverse<M> for A
+    where A: Reduce<M>,
+          ReduceOut<A,M>: ReducedModInverse<M> {
+    type Output = <ReduceOut<A,M> as ReducedModInverse<M>>::Output;
+}
+
+// Alias for the result of ModInverse.
+pub type ModInverseOut<A,M> = <A as ModInverse<M>>::Output;
+
+#[test]
+fn test_mod_inverse() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, ModInverseOut<U5,U1>);
+    assert_type_eq!(U1, ModInverseOut<U1,U2>);
+    assert_type_eq!(U1, ModInverseOut<U3,U2>);
+    assert_type_eq!(U3, ModInverseOut<U3,U8>);
+    assert_type_eq!(U4, ModInverseOut<U2,U7>);
+    assert_type_eq!(U5, ModInverseOut<U3,U7>);
+    assert_type_eq!(U6, ModInverseOut<U6,U7>);
+    assert_type_eq!(U9, ModInverseOut<U4,U35>);
+    assert_type_eq!(U11, ModInverseOut<U35,U12>);
+    assert_type_eq!(U82, ModInverseOut<U10,U91>);
+}
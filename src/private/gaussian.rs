@@ -0,0 +1,123 @@
+// src/private/gaussian.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, BitAnd, Mul};
+
+use typenum::bit::{Bit, B0, B1};
+use typenum::consts::{False, True};
+use typenum::int::{NInt, PInt, Z0};
+use typenum::marker_traits::NonZero;
+use typenum::operator_aliases::{And, Square, Sum};
+use typenum::uint::{Unsigned, UInt, UTerm};
+
+use super::PrivateIsPrime;
+
+
+// A type operator. The Output is the unsigned absolute value of the
+// signed integer Self.
+pub trait Magnitude {
+    type Output;
+}
+
+impl Magnitude for Z0 {
+    type Output = UTerm;
+}
+
+impl<U> Magnitude for PInt<U> where U: Unsigned + NonZero {
+    type Output = U;
+}
+
+impl<U> Magnitude for NInt<U> where U: Unsigned + NonZero {
+    type Output = U;
+}
+
+// Alias for the result of Magnitude.
+pub type MagnitudeOut<N> = <N as Magnitude>::Output;
+
+
+// A type operator. The Output is True if Self is congruent to 3
+// modulo 4, that is, if its two lowest bits are set.
+pub trait IsThreeModFour {
+    type Output: Bit;
+}
+
+impl IsThreeModFour for UTerm {
+    type Output = False;
+}
+
+impl<B> IsThreeModFour for UInt<UTerm,B> {
+    type Output = False;
+}
+
+impl<U,B> IsThreeModFour for UInt<UInt<U,B>,B0> {
+    type Output = False;
+}
+
+impl<U> IsThreeModFour for UInt<UInt<U,B0>,B1> {
+    type Output = False;
+}
+
+impl<U> IsThreeModFour for UInt<UInt<U,B1>,B1> {
+    type Output = True;
+}
+
+
+// A type operator for testing whether a+bi is a Gaussian prime, where
+// Self is |a| and Mb is |b|.
+//
+// When one component is zero, the other must be a rational prime
+// congruent to 3 modulo 4. Otherwise, the norm a^2+b^2 must be a
+// rational prime.
+pub trait PrivateIsGaussianPrime<Mb> {
+    type Output: Bit;
+}
+
+impl PrivateIsGaussianPrime<UTerm> for UTerm {
+    type Output = False;
+}
+
+impl<U,B> PrivateIsGaussianPrime<UInt<U,B>> for UTerm
+    where UInt<U,B>: PrivateIsPrime + IsThreeModFour,
+          <UInt<U,B> as PrivateIsPrime>::Output: BitAnd<<UInt<U,B> as IsThreeModFour>::Output>,
+          And<<UInt<U,B> as PrivateIsPrime>::Output, <UInt<U,B> as IsThreeModFour>::Output>: Bit {
+    type Output = And<<UInt<U,B> as PrivateIsPrime>::Output, <UInt<U,B> as IsThreeModFour>::Output>;
+}
+
+impl<U,B> PrivateIsGaussianPrime<UTerm> for UInt<U,B>
+    where UTerm: PrivateIsGaussianPrime<UInt<U,B>> {
+    type Output = <UTerm as PrivateIsGaussianPrime<UInt<U,B>>>::Output;
+}
+
+impl<Ua,Ba,Ub,Bb> PrivateIsGaussianPrime<UInt<Ub,Bb>> for UInt<Ua,Ba>
+    where UInt<Ua,Ba>: Mul,
+          UInt<Ub,Bb>: Mul,
+          Square<UInt<Ua,Ba>>: Add<Square<UInt<Ub,Bb>>>,
+          Sum<Square<UInt<Ua,Ba>>, Square<UInt<Ub,Bb>>>: PrivateIsPrime {
+    type Output = <Sum<Square<UInt<Ua,Ba>>, Square<UInt<Ub,Bb>>> as PrivateIsPrime>::Output;
+}
+
+#[test]
+fn test_is_gaussian_prime() {
+    use typenum::consts::*;
+    type T<A,B> = <A as PrivateIsGaussianPrime<B>>::Output;
+    assert_type_eq!(False, T<U0,U0>);
+    assert_type_eq!(False, T<U1,U0>);
+    assert_type_eq!(False, T<U2,U0>);
+    assert_type_eq!(True, T<U3,U0>);
+    assert_type_eq!(True, T<U0,U3>);
+    assert_type_eq!(False, T<U5,U0>);
+    assert_type_eq!(True, T<U0,U7>);
+    assert_type_eq!(False, T<U9,U0>);
+    assert_type_eq!(True, T<U1,U1>);
+    assert_type_eq!(True, T<U2,U1>);
+    assert_type_eq!(False, T<U2,U2>);
+    assert_type_eq!(True, T<U5,U4>);
+    assert_type_eq!(False, T<U3,U3>);
+}
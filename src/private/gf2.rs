@@ -0,0 +1,372 @@
+// src/private/gf2.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Arithmetic on polynomials over GF(2). A polynomial is encoded as an
+// unsigned integer whose bit k is the coefficient of x^k, so that
+// addition is XOR and the degree is the bit length minus one.
+
+use core::ops::{Add, BitAnd, BitXor, Div, Not, Shl, Shr, Sub};
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::{Bit, B0, B1};
+use typenum::consts::{False, True, U1, U2};
+use typenum::operator_aliases::{Add1, And, Length, Minimum, Quot, Shleft, Shright, Sub1, Xor};
+use typenum::type_operators::{Len, Min};
+use typenum::uint::{UInt, UTerm};
+
+use super::misc::{Double, DoubleOut, IsOne, IsOneOut};
+use super::primitive_root::{PrimeFactors, PrimeFactorsOut};
+
+
+// A type operator. Self has degree at most the degree of M. If the
+// degrees are equal, the Output is Self+M, which cancels the leading
+// term. Otherwise, the Output is Self unchanged.
+//
+// Adding M lowers Self exactly when the degrees are equal, so this is
+// the smaller of Self and Self+M.
+pub trait Gf2ReduceOnce<M> {
+    type Output;
+}
+
+impl<N,M> Gf2ReduceOnce<M> for N
+    where N: BitXor<M>,
+          N: Min<Xor<N,M>> {
+    type Output = Minimum<N, Xor<N,M>>;
+}
+
+// Alias for the result of Gf2ReduceOnce.
+type Gf2ReduceOnceOut<N,M> = <N as Gf2ReduceOnce<M>>::Output;
+
+
+// A type operator for the remainder of polynomial division.
+pub trait Gf2Reduce<M> {
+    type Output;
+}
+
+// If Self is zero, the result is always zero.
+impl<M> Gf2Reduce<M> for UTerm {
+    type Output = UTerm;
+}
+
+// (x*n+0)%m == reduce_once(x*(n%m))
+impl<Un,M> Gf2Reduce<M> for UInt<Un,B0>
+    where Un: Gf2Reduce<M>,
+          Gf2ReduceOut<Un,M>: Double,
+          DoubleOut<Gf2ReduceOut<Un,M>>: Gf2ReduceOnce<M> {
+    type Output = Gf2ReduceOnceOut<DoubleOut<Gf2ReduceOut<Un,M>>, M>;
+}
+
+// (x*n+1)%m == reduce_once(x*(n%m)+1)
+impl<Un,M> Gf2Reduce<M> for UInt<Un,B1>
+    where Un: Gf2Reduce<M>,
+          Gf2ReduceOut<Un,M>: Double,
+          DoubleOut<Gf2ReduceOut<Un,M>>: Add<B1>,
+          Add1<DoubleOut<Gf2ReduceOut<Un,M>>>: Gf2ReduceOnce<M> {
+    type Output = Gf2ReduceOnceOut<Add1<DoubleOut<Gf2ReduceOut<Un,M>>>, M>;
+}
+
+// Alias for the result of Gf2Reduce<M>.
+pub type Gf2ReduceOut<N,M> = <N as Gf2Reduce<M>>::Output;
+
+#[test]
+fn test_gf2_reduce() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, Gf2ReduceOut<U0,U7>);
+    assert_type_eq!(U3, Gf2ReduceOut<U3,U7>);
+    assert_type_eq!(U3, Gf2ReduceOut<U4,U7>); // x^2 == x+1
+    assert_type_eq!(U1, Gf2ReduceOut<U8,U7>); // x^3 == 1
+    assert_type_eq!(U0, Gf2ReduceOut<U5,U3>); // x^2+1 == (x+1)^2
+    assert_type_eq!(U27, Gf2ReduceOut<U256,U283>);
+}
+
+
+// A type operator for the greatest common divisor of two polynomials.
+pub trait Gf2Gcd<RHS> {
+    type Output;
+}
+
+impl<A> Gf2Gcd<UTerm> for A {
+    type Output = A;
+}
+
+impl<A,Ub,Bb> Gf2Gcd<UInt<Ub,Bb>> for A
+    where A: Gf2Reduce<UInt<Ub,Bb>>,
+          UInt<Ub,Bb>: Gf2Gcd<Gf2ReduceOut<A,UInt<Ub,Bb>>> {
+    type Output = Gf2GcdOut<UInt<Ub,Bb>, Gf2ReduceOut<A,UInt<Ub,Bb>>>;
+}
+
+// Alias for the result of Gf2Gcd.
+pub type Gf2GcdOut<A,B> = <A as Gf2Gcd<B>>::Output;
+
+#[test]
+fn test_gf2_gcd() {
+    use typenum::consts::*;
+    assert_type_eq!(U1, Gf2GcdOut<U7,U11>);
+    assert_type_eq!(U3, Gf2GcdOut<U5,U3>);
+    assert_type_eq!(U7, Gf2GcdOut<U21,U9>); // (x^2+x+1)^2 and (x+1)(x^2+x+1)
+}
+
+
+// A type operator for the product of the polynomials Self and B,
+// reduced modulo M. Self must already be reduced modulo M.
+//
+// The factor B is consumed from its least significant bit:
+//     a*(x*b+0) == x*(a*b)
+//     a*(x*b+1) == x*(a*b) + a
+pub trait Gf2MulMod<B,M> {
+    type Output;
+}
+
+impl<A,M> Gf2MulMod<UTerm,M> for A {
+    type Output = UTerm;
+}
+
+impl<A,Ub,M> Gf2MulMod<UInt<Ub,B0>,M> for A
+    where A: Gf2MulMod<Ub,M>,
+          Gf2MulModOut<A,Ub,M>: Double,
+          DoubleOut<Gf2MulModOut<A,Ub,M>>: Gf2ReduceOnce<M> {
+    type Output = Gf2ReduceOnceOut<DoubleOut<Gf2MulModOut<A,Ub,M>>, M>;
+}
+
+impl<A,Ub,M> Gf2MulMod<UInt<Ub,B1>,M> for A
+    where A: Gf2MulMod<Ub,M>,
+          Gf2MulModOut<A,Ub,M>: Double,
+          DoubleOut<Gf2MulModOut<A,Ub,M>>: Gf2ReduceOnce<M>,
+          Gf2ReduceOnceOut<DoubleOut<Gf2MulModOut<A,Ub,M>>, M>: BitXor<A> {
+    type Output = Xor<Gf2ReduceOnceOut<DoubleOut<Gf2MulModOut<A,Ub,M>>, M>, A>;
+}
+
+// Alias for the result of Gf2MulMod.
+pub type Gf2MulModOut<A,B,M> = <A as Gf2MulMod<B,M>>::Output;
+
+// Self squared, reduced modulo M.
+type Gf2SquareOut<A,M> = Gf2MulModOut<A,A,M>;
+
+#[test]
+fn test_gf2_mul_mod() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, Gf2MulModOut<U5,U0,U7>);
+    assert_type_eq!(U1, Gf2MulModOut<U2,U3,U7>); // x*(x+1) == x^2+x == 1
+    assert_type_eq!(U1, Gf2MulModOut<U83,U202,U283>); // inverses in the AES field
+}
+
+
+// A type operator for raising the polynomial Self to the power E,
+// reduced modulo M. Self must already be reduced modulo M. The
+// recursion is the same square-and-multiply as PrivateModPow.
+pub trait Gf2PowMod<E,M> {
+    type Output;
+}
+
+impl<A,M> Gf2PowMod<UTerm,M> for A
+    where U1: Gf2Reduce<M> {
+    type Output = Gf2ReduceOut<U1,M>;
+}
+
+impl<A,Ue,M> Gf2PowMod<UInt<Ue,B0>,M> for A
+    where A: Gf2PowMod<Ue,M>,
+          Gf2PowModOut<A,Ue,M>: Gf2MulMod<Gf2PowModOut<A,Ue,M>,M> {
+    type Output = Gf2SquareOut<Gf2PowModOut<A,Ue,M>, M>;
+}
+
+impl<A,Ue,M> Gf2PowMod<UInt<Ue,B1>,M> for A
+    where A: Gf2PowMod<Ue,M>,
+          Gf2PowModOut<A,Ue,M>: Gf2MulMod<Gf2PowModOut<A,Ue,M>,M>,
+          Gf2SquareOut<Gf2PowModOut<A,Ue,M>, M>: Gf2MulMod<A,M> {
+    type Output = Gf2MulModOut<Gf2SquareOut<Gf2PowModOut<A,Ue,M>, M>, A, M>;
+}
+
+// Alias for the result of Gf2PowMod.
+pub type Gf2PowModOut<A,E,M> = <A as Gf2PowMod<E,M>>::Output;
+
+#[test]
+fn test_gf2_pow_mod() {
+    use typenum::consts::*;
+    assert_type_eq!(U1, Gf2PowModOut<U2,U0,U7>);
+    assert_type_eq!(U1, Gf2PowModOut<U2,U3,U7>);
+    assert_type_eq!(U1, Gf2PowModOut<U2,U15,U19>);
+    assert_type_eq!(U1, Gf2PowModOut<U2,U5,U31>); // x has order 5
+}
+
+
+// A type operator for the Ben-Or irreducibility test. Self is
+// x^(2^i) modulo P, and the Output is True if gcd(P, x^(2^j)-x) is 1
+// for each of the next Count values of j.
+//
+// A polynomial of degree n is irreducible when this holds for every j
+// from 1 through n/2, since x^(2^j)-x is the product of all the
+// irreducible polynomials whose degree divides j.
+pub trait Gf2BenOr<P,Count> {
+    type Output: Bit;
+}
+
+impl<H,P> Gf2BenOr<P,UTerm> for H {
+    type Output = True;
+}
+
+impl<H,P,Uc,Bc> Gf2BenOr<P,UInt<Uc,Bc>> for H
+    where H: Gf2MulMod<H,P>,
+          Gf2SquareOut<H,P>: BitXor<U2>,
+          P: Gf2Gcd<Xor<Gf2SquareOut<H,P>, U2>>,
+          Gf2GcdOut<P, Xor<Gf2SquareOut<H,P>, U2>>: IsOne,
+          UInt<Uc,Bc>: Sub<B1>,
+          Gf2SquareOut<H,P>: Gf2BenOr<P, Sub1<UInt<Uc,Bc>>>,
+          IsOneOut<Gf2GcdOut<P, Xor<Gf2SquareOut<H,P>, U2>>>: BitAnd<Gf2BenOrOut<Gf2SquareOut<H,P>, P, Sub1<UInt<Uc,Bc>>>>,
+          And<IsOneOut<Gf2GcdOut<P, Xor<Gf2SquareOut<H,P>, U2>>>, Gf2BenOrOut<Gf2SquareOut<H,P>, P, Sub1<UInt<Uc,Bc>>>>: Bit {
+    type Output = And<IsOneOut<Gf2GcdOut<P, Xor<Gf2SquareOut<H,P>, U2>>>, Gf2BenOrOut<Gf2SquareOut<H,P>, P, Sub1<UInt<Uc,Bc>>>>;
+}
+
+// Alias for the result of Gf2BenOr.
+type Gf2BenOrOut<H,P,Count> = <H as Gf2BenOr<P,Count>>::Output;
+
+
+// A type operator for testing whether the polynomial Self is
+// irreducible over GF(2).
+pub trait PrivateIsIrreducibleGf2 {
+    type Output: Bit;
+}
+
+// 0 and 1 are not irreducible.
+impl PrivateIsIrreducibleGf2 for UTerm {
+    type Output = False;
+}
+
+impl PrivateIsIrreducibleGf2 for UInt<UTerm,B1> {
+    type Output = False;
+}
+
+// Polynomials of degree n > 0 apply the Ben-Or test for j up to n/2,
+// starting from x modulo Self.
+impl<U,Ba,Bb> PrivateIsIrreducibleGf2 for UInt<UInt<U,Ba>,Bb>
+    where Self: Len,
+          Length<Self>: Sub<B1>,
+          Sub1<Length<Self>>: Shr<B1>,
+          U2: Gf2Reduce<Self>,
+          Gf2ReduceOut<U2,Self>: Gf2BenOr<Self, Shright<Sub1<Length<Self>>, B1>> {
+    type Output = Gf2BenOrOut<Gf2ReduceOut<U2,Self>, Self, Shright<Sub1<Length<Self>>, B1>>;
+}
+
+#[test]
+fn test_is_irreducible_gf2() {
+    use typenum::consts::*;
+    type T<P> = <P as PrivateIsIrreducibleGf2>::Output;
+    assert_type_eq!(False, T<U0>);
+    assert_type_eq!(False, T<U1>);
+    assert_type_eq!(True, T<U2>);
+    assert_type_eq!(True, T<U3>);
+    assert_type_eq!(False, T<U4>);
+    assert_type_eq!(False, T<U5>);
+    assert_type_eq!(True, T<U7>);
+    assert_type_eq!(False, T<U9>);
+    assert_type_eq!(True, T<U11>);
+    assert_type_eq!(True, T<U13>);
+    assert_type_eq!(False, T<U17>);
+    assert_type_eq!(True, T<U19>);
+    assert_type_eq!(False, T<U21>);
+    assert_type_eq!(True, T<U25>);
+    assert_type_eq!(True, T<U31>);
+    assert_type_eq!(True, T<U283>);
+    assert_type_eq!(True, T<U285>);
+    assert_type_eq!(False, T<U287>);
+}
+
+
+// A type operator. The Output is True if Self, x modulo the
+// irreducible polynomial P, generates the multiplicative group of
+// GF(2)[x]/P. Order is the order of that group and Factors is a list
+// of the prime factors of Order.
+pub trait Gf2IsGenerator<P,Order,Factors> {
+    type Output: Bit;
+}
+
+impl<G,P,Order> Gf2IsGenerator<P,Order,ATerm> for G {
+    type Output = True;
+}
+
+impl<G,P,Order,Q,Qs> Gf2IsGenerator<P,Order,TArr<Q,Qs>> for G
+    where Order: Div<Q>,
+          G: Gf2PowMod<Quot<Order,Q>, P>,
+          Gf2PowModOut<G, Quot<Order,Q>, P>: IsOne,
+          IsOneOut<Gf2PowModOut<G, Quot<Order,Q>, P>>: Not,
+          <IsOneOut<Gf2PowModOut<G, Quot<Order,Q>, P>> as Not>::Output: BitAnd<Gf2IsGeneratorOut<G,P,Order,Qs>>,
+          G: Gf2IsGenerator<P,Order,Qs>,
+          And<<IsOneOut<Gf2PowModOut<G, Quot<Order,Q>, P>> as Not>::Output, Gf2IsGeneratorOut<G,P,Order,Qs>>: Bit {
+    type Output = And<<IsOneOut<Gf2PowModOut<G, Quot<Order,Q>, P>> as Not>::Output, Gf2IsGeneratorOut<G,P,Order,Qs>>;
+}
+
+// Alias for the result of Gf2IsGenerator.
+type Gf2IsGeneratorOut<G,P,Order,Factors> = <G as Gf2IsGenerator<P,Order,Factors>>::Output;
+
+
+// A type operator for testing whether the polynomial Self is
+// primitive over GF(2), that is, irreducible with x generating the
+// multiplicative group of the field GF(2)[x]/Self.
+pub trait PrivateIsPrimitiveGf2 {
+    type Output: Bit;
+}
+
+// 0 is not primitive.
+impl PrivateIsPrimitiveGf2 for UTerm {
+    type Output = False;
+}
+
+// A polynomial without a constant term is divisible by x, and x
+// itself is not primitive since it is 0 modulo x.
+impl<U> PrivateIsPrimitiveGf2 for UInt<U,B0> {
+    type Output = False;
+}
+
+impl<U> PrivateIsPrimitiveGf2 for UInt<U,B1>
+    where Self: PrivateIsIrreducibleGf2,
+          Self: Gf2PrimitiveBranch<<Self as PrivateIsIrreducibleGf2>::Output> {
+    type Output = <Self as Gf2PrimitiveBranch<<Self as PrivateIsIrreducibleGf2>::Output>>::Output;
+}
+
+
+pub trait Gf2PrimitiveBranch<SelfIsIrreducible> {
+    type Output: Bit;
+}
+
+// A reducible polynomial is not primitive.
+impl<P> Gf2PrimitiveBranch<False> for P {
+    type Output = False;
+}
+
+// For an irreducible polynomial of degree n, the multiplicative group
+// has order 2^n-1.
+impl<P> Gf2PrimitiveBranch<True> for P
+    where P: Len,
+          Length<P>: Sub<B1>,
+          U1: Shl<Sub1<Length<P>>>,
+          Shleft<U1, Sub1<Length<P>>>: Sub<B1>,
+          Sub1<Shleft<U1, Sub1<Length<P>>>>: PrimeFactors,
+          U2: Gf2Reduce<P>,
+          Gf2ReduceOut<U2,P>: Gf2IsGenerator<P, Sub1<Shleft<U1, Sub1<Length<P>>>>, PrimeFactorsOut<Sub1<Shleft<U1, Sub1<Length<P>>>>>> {
+    type Output = Gf2IsGeneratorOut<Gf2ReduceOut<U2,P>, P, Sub1<Shleft<U1, Sub1<Length<P>>>>, PrimeFactorsOut<Sub1<Shleft<U1, Sub1<Length<P>>>>>>;
+}
+
+#[test]
+fn test_is_primitive_gf2() {
+    use typenum::consts::*;
+    type T<P> = <P as PrivateIsPrimitiveGf2>::Output;
+    assert_type_eq!(False, T<U0>);
+    assert_type_eq!(False, T<U1>);
+    assert_type_eq!(False, T<U2>);
+    assert_type_eq!(True, T<U3>);
+    assert_type_eq!(True, T<U7>);
+    assert_type_eq!(False, T<U9>);
+    assert_type_eq!(True, T<U11>);
+    assert_type_eq!(True, T<U13>);
+    assert_type_eq!(True, T<U19>);
+    assert_type_eq!(True, T<U25>);
+    assert_type_eq!(False, T<U31>);
+    assert_type_eq!(False, T<U283>);
+    assert_type_eq!(True, T<U285>);
+}
@@ -0,0 +1,547 @@
+// src/private/gfp.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Arithmetic on polynomials over GF(p). A polynomial is a typenum
+// array of its coefficients, constant term first, so that
+// TArr<A,As> is A + x*As, in the same way that UInt<U,B> is B + 2*U.
+//
+// Residues modulo a monic polynomial x^k + F of degree k are arrays of
+// exactly k coefficients. Such a modulus is passed around as its tail
+// F, the array of its k lower coefficients.
+
+use core::ops::{Add, BitAnd, Mul, Shr, Sub};
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::{Bit, B0, B1};
+use typenum::consts::{False, True, U0, U1};
+use typenum::marker_traits::Unsigned;
+use typenum::operator_aliases::{And, Diff, Length, Prod, Shright, Sub1, Sum};
+use typenum::type_operators::Len;
+use typenum::uint::{UInt, UTerm};
+
+use super::euclid::{ModInverse, ModInverseOut};
+use super::reduction::{Reduce, ReduceOut};
+
+
+// A type operator for Self + C*Y, coefficient by coefficient, modulo
+// P. Self and Y must have the same length.
+pub trait GfpAxpy<C,Y,P> {
+    type Output;
+}
+
+impl<C,P> GfpAxpy<C,ATerm,P> for ATerm {
+    type Output = ATerm;
+}
+
+impl<A,As,C,Y,Ys,P> GfpAxpy<C,TArr<Y,Ys>,P> for TArr<A,As>
+    where C: Mul<Y>,
+          A: Add<Prod<C,Y>>,
+          Sum<A,Prod<C,Y>>: Reduce<P>,
+          As: GfpAxpy<C,Ys,P> {
+    type Output = TArr<ReduceOut<Sum<A,Prod<C,Y>>,P>, GfpAxpyOut<As,C,Ys,P>>;
+}
+
+// Alias for the result of GfpAxpy.
+pub type GfpAxpyOut<A,C,Y,P> = <A as GfpAxpy<C,Y,P>>::Output;
+
+
+// A type operator for the array of zeros with the length of Self.
+pub trait GfpZero {
+    type Output;
+}
+
+impl GfpZero for ATerm {
+    type Output = ATerm;
+}
+
+impl<A,As> GfpZero for TArr<A,As> where As: GfpZero {
+    type Output = TArr<U0, GfpZeroOut<As>>;
+}
+
+// Alias for the result of GfpZero.
+pub type GfpZeroOut<A> = <A as GfpZero>::Output;
+
+
+// A type operator for the polynomial 1 with the length of Self. It is
+// the residue of 1 modulo a tail Self of length at least 1.
+pub trait GfpOne {
+    type Output;
+}
+
+impl GfpOne for ATerm {
+    type Output = ATerm;
+}
+
+impl<A,As> GfpOne for TArr<A,As> where As: GfpZero {
+    type Output = TArr<U1, GfpZeroOut<As>>;
+}
+
+// Alias for the result of GfpOne.
+pub type GfpOneOut<A> = <A as GfpOne>::Output;
+
+
+// A type operator. The Output is Self with C inserted at the front
+// and its last coefficient removed, and Carry is that last
+// coefficient. This multiplies by x and splits off the x^k term.
+pub trait GfpShiftIn<C> {
+    type Output;
+    type Carry;
+}
+
+impl<C> GfpShiftIn<C> for ATerm {
+    type Output = ATerm;
+    type Carry = C;
+}
+
+impl<A,As,C> GfpShiftIn<C> for TArr<A,As> where As: GfpShiftIn<A> {
+    type Output = TArr<C, <As as GfpShiftIn<A>>::Output>;
+    type Carry = <As as GfpShiftIn<A>>::Carry;
+}
+
+
+// A type operator for x*Self modulo the tail F, where Self is a
+// residue modulo F. The x^k term that is shifted out is replaced by
+// its residue, -F.
+pub trait GfpMulX<F,P> {
+    type Output;
+}
+
+impl<A,F,P> GfpMulX<F,P> for A
+    where A: GfpShiftIn<U0>,
+          P: Sub<<A as GfpShiftIn<U0>>::Carry>,
+          <A as GfpShiftIn<U0>>::Output: GfpAxpy<Diff<P, <A as GfpShiftIn<U0>>::Carry>, F, P> {
+    type Output = GfpAxpyOut<<A as GfpShiftIn<U0>>::Output, Diff<P, <A as GfpShiftIn<U0>>::Carry>, F, P>;
+}
+
+// Alias for the result of GfpMulX.
+pub type GfpMulXOut<A,F,P> = <A as GfpMulX<F,P>>::Output;
+
+
+// A type operator for the polynomial Self modulo the tail F. Self may
+// have any length and unreduced coefficients.
+//
+// The coefficients are consumed from the highest, as in Gf2Reduce:
+//     (a + x*as) % f == x*(as % f) + a
+pub trait GfpRem<F,P> {
+    type Output;
+}
+
+impl<F,P> GfpRem<F,P> for ATerm where F: GfpZero {
+    type Output = GfpZeroOut<F>;
+}
+
+impl<A,As,F,P> GfpRem<F,P> for TArr<A,As>
+    where As: GfpRem<F,P>,
+          GfpRemOut<As,F,P>: GfpMulX<F,P>,
+          F: GfpOne,
+          GfpMulXOut<GfpRemOut<As,F,P>,F,P>: GfpAxpy<A, GfpOneOut<F>, P> {
+    type Output = GfpAxpyOut<GfpMulXOut<GfpRemOut<As,F,P>,F,P>, A, GfpOneOut<F>, P>;
+}
+
+// Alias for the result of GfpRem.
+pub type GfpRemOut<A,F,P> = <A as GfpRem<F,P>>::Output;
+
+#[test]
+fn test_gfp_rem() {
+    use typenum::consts::*;
+    // x^2 == -1 == 2 modulo x^2+1 over GF(3)
+    assert_type_eq!(tarr![U2, U0], GfpRemOut<tarr![U0, U0, U1], tarr![U1, U0], U3>);
+    // x^3 == -x modulo x^2+1
+    assert_type_eq!(tarr![U0, U2], GfpRemOut<tarr![U0, U0, U0, U1], tarr![U1, U0], U3>);
+    assert_type_eq!(tarr![U1, U1], GfpRemOut<tarr![U7, U4], tarr![U1, U0], U3>);
+    assert_type_eq!(ATerm, GfpRemOut<tarr![U4, U2], ATerm, U5>);
+}
+
+
+// A type operator for the product of the residues Self and B modulo
+// the tail F. The factor B is consumed from its highest coefficient:
+//     a*(b + x*bs) == x*(a*bs) + b*a
+pub trait GfpMulMod<B,F,P> {
+    type Output;
+}
+
+impl<A,F,P> GfpMulMod<ATerm,F,P> for A where F: GfpZero {
+    type Output = GfpZeroOut<F>;
+}
+
+impl<A,B,Bs,F,P> GfpMulMod<TArr<B,Bs>,F,P> for A
+    where A: GfpMulMod<Bs,F,P>,
+          GfpMulModOut<A,Bs,F,P>: GfpMulX<F,P>,
+          GfpMulXOut<GfpMulModOut<A,Bs,F,P>,F,P>: GfpAxpy<B,A,P> {
+    type Output = GfpAxpyOut<GfpMulXOut<GfpMulModOut<A,Bs,F,P>,F,P>, B, A, P>;
+}
+
+// Alias for the result of GfpMulMod.
+pub type GfpMulModOut<A,B,F,P> = <A as GfpMulMod<B,F,P>>::Output;
+
+// Self squared modulo the tail F.
+type GfpSquareOut<A,F,P> = GfpMulModOut<A,A,F,P>;
+
+#[test]
+fn test_gfp_mul_mod() {
+    use typenum::consts::*;
+    // (1+x)*(1+2x) == 1 + 2x^2 == 1 + 2*2 == 2 modulo x^2+1 over GF(3)
+    assert_type_eq!(tarr![U2, U0], GfpMulModOut<tarr![U1, U1], tarr![U1, U2], tarr![U1, U0], U3>);
+    // x*x == 3 modulo x^2-3 over GF(7)
+    assert_type_eq!(tarr![U3, U0], GfpMulModOut<tarr![U0, U1], tarr![U0, U1], tarr![U4, U0], U7>);
+}
+
+
+// A type operator for raising the residue Self to the power E modulo
+// the tail F, by the same square-and-multiply as Gf2PowMod.
+pub trait GfpPowMod<E,F,P> {
+    type Output;
+}
+
+impl<A,F,P> GfpPowMod<UTerm,F,P> for A where F: GfpOne {
+    type Output = GfpOneOut<F>;
+}
+
+impl<A,Ue,Be,F,P> GfpPowMod<UInt<Ue,Be>,F,P> for A
+    where A: GfpPowMod<Ue,F,P>,
+          GfpPowModOut<A,Ue,F,P>: GfpMulMod<GfpPowModOut<A,Ue,F,P>,F,P>,
+          GfpSquareOut<GfpPowModOut<A,Ue,F,P>,F,P>: GfpMulPowBit<A,Be,F,P> {
+    type Output = <GfpSquareOut<GfpPowModOut<A,Ue,F,P>,F,P> as GfpMulPowBit<A,Be,F,P>>::Output;
+}
+
+// Alias for the result of GfpPowMod.
+pub type GfpPowModOut<A,E,F,P> = <A as GfpPowMod<E,F,P>>::Output;
+
+
+// A type operator for one bit of GfpPowMod: Self times A if the bit
+// B is set, or Self unchanged.
+pub trait GfpMulPowBit<A,B,F,P> {
+    type Output;
+}
+
+impl<S,A,F,P> GfpMulPowBit<A,B0,F,P> for S {
+    type Output = S;
+}
+
+impl<S,A,F,P> GfpMulPowBit<A,B1,F,P> for S where S: GfpMulMod<A,F,P> {
+    type Output = GfpMulModOut<S,A,F,P>;
+}
+
+#[test]
+fn test_gfp_pow_mod() {
+    use typenum::consts::*;
+    // x has order 8 modulo x^2+x+2 over GF(3), which is primitive
+    type F = tarr![U2, U1];
+    type X = tarr![U0, U1];
+    assert_type_eq!(tarr![U1, U0], GfpPowModOut<X, U0, F, U3>);
+    assert_type_eq!(tarr![U1, U0], GfpPowModOut<X, U8, F, U3>);
+    assert_type_eq!(tarr![U2, U0], GfpPowModOut<X, U4, F, U3>);
+}
+
+
+// A type operator. The Output is Self with its highest zero
+// coefficients removed, so that the last coefficient of a nonzero
+// polynomial is its leading coefficient. The zero polynomial is
+// ATerm.
+pub trait GfpNormalize {
+    type Output;
+}
+
+impl GfpNormalize for ATerm {
+    type Output = ATerm;
+}
+
+impl<A,As> GfpNormalize for TArr<A,As>
+    where As: GfpNormalize,
+          GfpNormalizeOut<As>: GfpCons<A> {
+    type Output = <GfpNormalizeOut<As> as GfpCons<A>>::Output;
+}
+
+// Alias for the result of GfpNormalize.
+pub type GfpNormalizeOut<A> = <A as GfpNormalize>::Output;
+
+
+// A type operator for A + x*Self, where Self is normalized, dropping
+// A if the result would be a lone zero.
+pub trait GfpCons<A> {
+    type Output;
+}
+
+impl GfpCons<UTerm> for ATerm {
+    type Output = ATerm;
+}
+
+impl<U,B> GfpCons<UInt<U,B>> for ATerm {
+    type Output = TArr<UInt<U,B>, ATerm>;
+}
+
+impl<A,V,Vs> GfpCons<A> for TArr<V,Vs> {
+    type Output = TArr<A, TArr<V,Vs>>;
+}
+
+
+// A type operator splitting the nonempty array Self into its last
+// element and the elements before it.
+pub trait GfpSplitLast {
+    type Init;
+    type Last;
+}
+
+impl<A> GfpSplitLast for TArr<A,ATerm> {
+    type Init = ATerm;
+    type Last = A;
+}
+
+impl<A,V,Vs> GfpSplitLast for TArr<A,TArr<V,Vs>> where TArr<V,Vs>: GfpSplitLast {
+    type Init = TArr<A, <TArr<V,Vs> as GfpSplitLast>::Init>;
+    type Last = <TArr<V,Vs> as GfpSplitLast>::Last;
+}
+
+
+// A type operator for the tail of the monic polynomial with the same
+// roots as the normalized nonzero polynomial Self: the lower
+// coefficients, divided by the leading coefficient.
+pub trait GfpMonicTail<P> {
+    type Output;
+}
+
+impl<A,P> GfpMonicTail<P> for A
+    where A: GfpSplitLast,
+          <A as GfpSplitLast>::Last: ModInverse<P>,
+          <A as GfpSplitLast>::Init: GfpZero,
+          GfpZeroOut<<A as GfpSplitLast>::Init>: GfpAxpy<ModInverseOut<<A as GfpSplitLast>::Last, P>, <A as GfpSplitLast>::Init, P> {
+    type Output = GfpAxpyOut<GfpZeroOut<<A as GfpSplitLast>::Init>, ModInverseOut<<A as GfpSplitLast>::Last, P>, <A as GfpSplitLast>::Init, P>;
+}
+
+// Alias for the result of GfpMonicTail.
+pub type GfpMonicTailOut<A,P> = <A as GfpMonicTail<P>>::Output;
+
+#[test]
+fn test_gfp_monic_tail() {
+    use typenum::consts::*;
+    assert_type_eq!(tarr![U2, U1], GfpMonicTailOut<tarr![U2, U1, U1], U3>);
+    // (4 + 2x)/2 == 2 + x over GF(5)
+    assert_type_eq!(tarr![U2], GfpMonicTailOut<tarr![U4, U2], U5>);
+    assert_type_eq!(ATerm, GfpMonicTailOut<tarr![U3], U5>);
+}
+
+
+// A type operator for the greatest common divisor of two normalized
+// polynomials, up to a constant factor. The Output is normalized.
+pub trait GfpGcd<B,P> {
+    type Output;
+}
+
+impl<A,P> GfpGcd<ATerm,P> for A {
+    type Output = A;
+}
+
+impl<A,B,Bs,P> GfpGcd<TArr<B,Bs>,P> for A
+    where TArr<B,Bs>: GfpMonicTail<P>,
+          A: GfpRem<GfpMonicTailOut<TArr<B,Bs>,P>, P>,
+          GfpRemOut<A, GfpMonicTailOut<TArr<B,Bs>,P>, P>: GfpNormalize,
+          TArr<B,Bs>: GfpGcd<GfpNormalizeOut<GfpRemOut<A, GfpMonicTailOut<TArr<B,Bs>,P>, P>>, P> {
+    type Output = GfpGcdOut<TArr<B,Bs>, GfpNormalizeOut<GfpRemOut<A, GfpMonicTailOut<TArr<B,Bs>,P>, P>>, P>;
+}
+
+// Alias for the result of GfpGcd.
+pub type GfpGcdOut<A,B,P> = <A as GfpGcd<B,P>>::Output;
+
+
+// A type operator. The Output is True if the normalized polynomial
+// Self is a nonzero constant.
+pub trait GfpIsUnit {
+    type Output: Bit;
+}
+
+impl GfpIsUnit for ATerm {
+    type Output = False;
+}
+
+impl<A> GfpIsUnit for TArr<A,ATerm> {
+    type Output = True;
+}
+
+impl<A,V,Vs> GfpIsUnit for TArr<A,TArr<V,Vs>> {
+    type Output = False;
+}
+
+// Alias for the result of GfpIsUnit.
+type GfpIsUnitOut<A> = <A as GfpIsUnit>::Output;
+
+#[test]
+fn test_gfp_gcd() {
+    use typenum::consts::*;
+    // x^2-1 and x^2+x over GF(5) share the factor x+1
+    type G = GfpGcdOut<tarr![U4, U0, U1], tarr![U0, U1, U1], U5>;
+    assert_type_eq!(G, tarr![U4, U4]);
+    assert_type_eq!(True, GfpIsUnitOut<GfpGcdOut<tarr![U1, U0, U1], tarr![U0, U1], U3>>);
+    assert_type_eq!(False, GfpIsUnitOut<GfpGcdOut<tarr![U1, U0, U1], ATerm, U3>>);
+}
+
+
+// H - X, normalized. With H the residue of x^(p^j) and X that of x,
+// this is the polynomial whose gcd Ben-Or's test takes.
+type GfpBenOrTermOut<H,X,P> = GfpNormalizeOut<GfpAxpyOut<H, Sub1<P>, X, P>>;
+
+
+// A type operator for the Ben-Or irreducibility test, as in
+// Gf2BenOr. Self is x^(p^i) modulo the tail F of the normalized
+// polynomial N, X is x modulo F, and the Output is True if
+// gcd(N, x^(p^j)-x) is constant for each of the next Count values of
+// j.
+pub trait GfpBenOr<X,F,N,P,Count> {
+    type Output: Bit;
+}
+
+impl<H,X,F,N,P> GfpBenOr<X,F,N,P,UTerm> for H {
+    type Output = True;
+}
+
+impl<H,X,F,N,P,Uc,Bc> GfpBenOr<X,F,N,P,UInt<Uc,Bc>> for H
+    where H: GfpPowMod<P,F,P>,
+          P: Sub<B1>,
+          GfpPowModOut<H,P,F,P>: GfpAxpy<Sub1<P>, X, P>,
+          GfpAxpyOut<GfpPowModOut<H,P,F,P>, Sub1<P>, X, P>: GfpNormalize,
+          N: GfpGcd<GfpBenOrTermOut<GfpPowModOut<H,P,F,P>,X,P>, P>,
+          GfpGcdOut<N, GfpBenOrTermOut<GfpPowModOut<H,P,F,P>,X,P>, P>: GfpIsUnit,
+          UInt<Uc,Bc>: Sub<B1>,
+          GfpPowModOut<H,P,F,P>: GfpBenOr<X,F,N,P,Sub1<UInt<Uc,Bc>>>,
+          GfpIsUnitOut<GfpGcdOut<N, GfpBenOrTermOut<GfpPowModOut<H,P,F,P>,X,P>, P>>: BitAnd<GfpBenOrOut<GfpPowModOut<H,P,F,P>,X,F,N,P,Sub1<UInt<Uc,Bc>>>>,
+          And<GfpIsUnitOut<GfpGcdOut<N, GfpBenOrTermOut<GfpPowModOut<H,P,F,P>,X,P>, P>>, GfpBenOrOut<GfpPowModOut<H,P,F,P>,X,F,N,P,Sub1<UInt<Uc,Bc>>>>: Bit {
+    type Output = And<GfpIsUnitOut<GfpGcdOut<N, GfpBenOrTermOut<GfpPowModOut<H,P,F,P>,X,P>, P>>, GfpBenOrOut<GfpPowModOut<H,P,F,P>,X,F,N,P,Sub1<UInt<Uc,Bc>>>>;
+}
+
+// Alias for the result of GfpBenOr.
+type GfpBenOrOut<H,X,F,N,P,Count> = <H as GfpBenOr<X,F,N,P,Count>>::Output;
+
+
+// A type operator for the normalized polynomial Self with its
+// coefficients reduced modulo P.
+pub trait GfpReduced<P> {
+    type Output;
+}
+
+impl<A,P> GfpReduced<P> for A
+    where A: GfpAxpy<U0,A,P>,
+          GfpAxpyOut<A,U0,A,P>: GfpNormalize {
+    type Output = GfpNormalizeOut<GfpAxpyOut<A,U0,A,P>>;
+}
+
+// Alias for the result of GfpReduced.
+pub type GfpReducedOut<A,P> = <A as GfpReduced<P>>::Output;
+
+
+// A type operator for testing whether the polynomial Self is
+// irreducible over GF(P).
+pub trait PrivateIsIrreducibleFp<P> {
+    type Output: Bit;
+}
+
+impl<A,P> PrivateIsIrreducibleFp<P> for A
+    where A: GfpReduced<P>,
+          GfpReducedOut<A,P>: GfpIrreducibleBranch<P> {
+    type Output = <GfpReducedOut<A,P> as GfpIrreducibleBranch<P>>::Output;
+}
+
+
+// The irreducibility test on a normalized polynomial Self.
+pub trait GfpIrreducibleBranch<P> {
+    type Output: Bit;
+}
+
+// 0 and the other constants are not irreducible.
+impl<P> GfpIrreducibleBranch<P> for ATerm {
+    type Output = False;
+}
+
+impl<A,P> GfpIrreducibleBranch<P> for TArr<A,ATerm> {
+    type Output = False;
+}
+
+// Polynomials of degree k > 0 apply the Ben-Or test for j up to k/2,
+// starting from x modulo Self.
+impl<A,V,Vs,P> GfpIrreducibleBranch<P> for TArr<A,TArr<V,Vs>>
+    where Self: GfpMonicTail<P>,
+          GfpMonicTailOut<Self,P>: Len + GfpOne,
+          Length<GfpMonicTailOut<Self,P>>: Shr<B1>,
+          GfpOneOut<GfpMonicTailOut<Self,P>>: GfpMulX<GfpMonicTailOut<Self,P>, P>,
+          GfpXOut<Self,P>: GfpBenOr<GfpXOut<Self,P>, GfpMonicTailOut<Self,P>, Self, P, Shright<Length<GfpMonicTailOut<Self,P>>, B1>> {
+    type Output = GfpBenOrOut<GfpXOut<Self,P>, GfpXOut<Self,P>, GfpMonicTailOut<Self,P>, Self, P, Shright<Length<GfpMonicTailOut<Self,P>>, B1>>;
+}
+
+// x modulo the normalized polynomial N.
+type GfpXOut<N,P> = GfpMulXOut<GfpOneOut<GfpMonicTailOut<N,P>>, GfpMonicTailOut<N,P>, P>;
+
+#[test]
+fn test_is_irreducible_fp() {
+    use typenum::consts::*;
+    type T<A,P> = <A as PrivateIsIrreducibleFp<P>>::Output;
+    assert_type_eq!(False, T<ATerm, U3>);
+    assert_type_eq!(False, T<tarr![U2], U3>);
+    assert_type_eq!(False, T<tarr![U3, U0, U3], U3>);
+    assert_type_eq!(True, T<tarr![U2, U1], U3>);
+    assert_type_eq!(True, T<tarr![U1, U0, U1], U3>); // x^2+1
+    assert_type_eq!(False, T<tarr![U1, U0, U1], U5>); // == (x+2)(x+3)
+    assert_type_eq!(False, T<tarr![U1, U0, U1], U2>); // == (x+1)^2
+    assert_type_eq!(True, T<tarr![U2, U2, U4], U5>); // 2(x^2+3x+3)
+    assert_type_eq!(True, T<tarr![U2, U0, U0, U1], U7>); // x^3+2
+    assert_type_eq!(False, T<tarr![U1, U0, U0, U1], U7>); // x^3+1 has the root -1
+    assert_type_eq!(True, T<tarr![U2, U0, U0, U0, U1], U5>); // x^4+2
+    assert_type_eq!(False, T<tarr![U4, U0, U0, U0, U1], U5>); // x^4-1
+    // x^4+1 has no roots over GF(3) but is (x^2+x+2)(x^2+2x+2)
+    assert_type_eq!(False, T<tarr![U1, U0, U0, U0, U1], U3>);
+}
+
+
+// A type operator for the degree of the irreducible polynomial Self
+// and the tail of its monic multiple, which define GF(P^Degree).
+pub trait PrivateFpModulus<P> {
+    type Degree;
+    type Tail;
+}
+
+impl<A,P> PrivateFpModulus<P> for A
+    where A: GfpReduced<P>,
+          GfpReducedOut<A,P>: GfpMonicTail<P>,
+          GfpMonicTailOut<GfpReducedOut<A,P>,P>: Len {
+    type Degree = Length<GfpMonicTailOut<GfpReducedOut<A,P>,P>>;
+    type Tail = GfpMonicTailOut<GfpReducedOut<A,P>,P>;
+}
+
+
+// Writing the coefficients of the tail of Self, the monic multiple of
+// an irreducible polynomial reduced modulo P without its leading 1, to
+// the start of a slice, which must hold at least Degree elements, for
+// `Fpk`.
+pub trait FillTail<P> {
+    fn fill_tail(out: &mut [u64]);
+}
+
+impl<A,P> FillTail<P> for A
+    where A: PrivateFpModulus<P>,
+          <A as PrivateFpModulus<P>>::Tail: GfpCoefficients {
+    fn fill_tail(out: &mut [u64]) {
+        <A as PrivateFpModulus<P>>::Tail::fill(out)
+    }
+}
+
+
+// Runtime access to an array of coefficients.
+pub trait GfpCoefficients {
+    // Write the coefficients to the start of out.
+    fn fill(out: &mut [u64]);
+}
+
+impl GfpCoefficients for ATerm {
+    fn fill(_: &mut [u64]) {}
+}
+
+impl<A,As> GfpCoefficients for TArr<A,As> where A: Unsigned, As: GfpCoefficients {
+    fn fill(out: &mut [u64]) {
+        out[0] = A::U64;
+        As::fill(&mut out[1..]);
+    }
+}
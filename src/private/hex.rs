@@ -0,0 +1,1435 @@
+// src/private/hex.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// DO NOT EDIT THIS FILE DIRECTLY!
+// This file is the output of util/generate_hex_tables.py
+use typenum::bit::{B0, B1};
+
+// The hexadecimal digits.
+pub struct H0;
+pub struct H1;
+pub struct H2;
+pub struct H3;
+pub struct H4;
+pub struct H5;
+pub struct H6;
+pub struct H7;
+pub struct H8;
+pub struct H9;
+pub struct H10;
+pub struct H11;
+pub struct H12;
+pub struct H13;
+pub struct H14;
+pub struct H15;
+
+// A two-digit number, most significant digit first.
+pub struct Byte<Hi,Lo>(Hi, Lo);
+
+// Self*Rhs.
+pub trait HexMul<Rhs> {
+    type Output;
+}
+impl HexMul<H0> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H2> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H3> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H4> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H5> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H6> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H7> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H8> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H9> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H10> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H11> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H12> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H13> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H14> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H15> for H0 { type Output = Byte<H0,H0>; }
+impl HexMul<H0> for H1 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H1 { type Output = Byte<H0,H1>; }
+impl HexMul<H2> for H1 { type Output = Byte<H0,H2>; }
+impl HexMul<H3> for H1 { type Output = Byte<H0,H3>; }
+impl HexMul<H4> for H1 { type Output = Byte<H0,H4>; }
+impl HexMul<H5> for H1 { type Output = Byte<H0,H5>; }
+impl HexMul<H6> for H1 { type Output = Byte<H0,H6>; }
+impl HexMul<H7> for H1 { type Output = Byte<H0,H7>; }
+impl HexMul<H8> for H1 { type Output = Byte<H0,H8>; }
+impl HexMul<H9> for H1 { type Output = Byte<H0,H9>; }
+impl HexMul<H10> for H1 { type Output = Byte<H0,H10>; }
+impl HexMul<H11> for H1 { type Output = Byte<H0,H11>; }
+impl HexMul<H12> for H1 { type Output = Byte<H0,H12>; }
+impl HexMul<H13> for H1 { type Output = Byte<H0,H13>; }
+impl HexMul<H14> for H1 { type Output = Byte<H0,H14>; }
+impl HexMul<H15> for H1 { type Output = Byte<H0,H15>; }
+impl HexMul<H0> for H2 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H2 { type Output = Byte<H0,H2>; }
+impl HexMul<H2> for H2 { type Output = Byte<H0,H4>; }
+impl HexMul<H3> for H2 { type Output = Byte<H0,H6>; }
+impl HexMul<H4> for H2 { type Output = Byte<H0,H8>; }
+impl HexMul<H5> for H2 { type Output = Byte<H0,H10>; }
+impl HexMul<H6> for H2 { type Output = Byte<H0,H12>; }
+impl HexMul<H7> for H2 { type Output = Byte<H0,H14>; }
+impl HexMul<H8> for H2 { type Output = Byte<H1,H0>; }
+impl HexMul<H9> for H2 { type Output = Byte<H1,H2>; }
+impl HexMul<H10> for H2 { type Output = Byte<H1,H4>; }
+impl HexMul<H11> for H2 { type Output = Byte<H1,H6>; }
+impl HexMul<H12> for H2 { type Output = Byte<H1,H8>; }
+impl HexMul<H13> for H2 { type Output = Byte<H1,H10>; }
+impl HexMul<H14> for H2 { type Output = Byte<H1,H12>; }
+impl HexMul<H15> for H2 { type Output = Byte<H1,H14>; }
+impl HexMul<H0> for H3 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H3 { type Output = Byte<H0,H3>; }
+impl HexMul<H2> for H3 { type Output = Byte<H0,H6>; }
+impl HexMul<H3> for H3 { type Output = Byte<H0,H9>; }
+impl HexMul<H4> for H3 { type Output = Byte<H0,H12>; }
+impl HexMul<H5> for H3 { type Output = Byte<H0,H15>; }
+impl HexMul<H6> for H3 { type Output = Byte<H1,H2>; }
+impl HexMul<H7> for H3 { type Output = Byte<H1,H5>; }
+impl HexMul<H8> for H3 { type Output = Byte<H1,H8>; }
+impl HexMul<H9> for H3 { type Output = Byte<H1,H11>; }
+impl HexMul<H10> for H3 { type Output = Byte<H1,H14>; }
+impl HexMul<H11> for H3 { type Output = Byte<H2,H1>; }
+impl HexMul<H12> for H3 { type Output = Byte<H2,H4>; }
+impl HexMul<H13> for H3 { type Output = Byte<H2,H7>; }
+impl HexMul<H14> for H3 { type Output = Byte<H2,H10>; }
+impl HexMul<H15> for H3 { type Output = Byte<H2,H13>; }
+impl HexMul<H0> for H4 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H4 { type Output = Byte<H0,H4>; }
+impl HexMul<H2> for H4 { type Output = Byte<H0,H8>; }
+impl HexMul<H3> for H4 { type Output = Byte<H0,H12>; }
+impl HexMul<H4> for H4 { type Output = Byte<H1,H0>; }
+impl HexMul<H5> for H4 { type Output = Byte<H1,H4>; }
+impl HexMul<H6> for H4 { type Output = Byte<H1,H8>; }
+impl HexMul<H7> for H4 { type Output = Byte<H1,H12>; }
+impl HexMul<H8> for H4 { type Output = Byte<H2,H0>; }
+impl HexMul<H9> for H4 { type Output = Byte<H2,H4>; }
+impl HexMul<H10> for H4 { type Output = Byte<H2,H8>; }
+impl HexMul<H11> for H4 { type Output = Byte<H2,H12>; }
+impl HexMul<H12> for H4 { type Output = Byte<H3,H0>; }
+impl HexMul<H13> for H4 { type Output = Byte<H3,H4>; }
+impl HexMul<H14> for H4 { type Output = Byte<H3,H8>; }
+impl HexMul<H15> for H4 { type Output = Byte<H3,H12>; }
+impl HexMul<H0> for H5 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H5 { type Output = Byte<H0,H5>; }
+impl HexMul<H2> for H5 { type Output = Byte<H0,H10>; }
+impl HexMul<H3> for H5 { type Output = Byte<H0,H15>; }
+impl HexMul<H4> for H5 { type Output = Byte<H1,H4>; }
+impl HexMul<H5> for H5 { type Output = Byte<H1,H9>; }
+impl HexMul<H6> for H5 { type Output = Byte<H1,H14>; }
+impl HexMul<H7> for H5 { type Output = Byte<H2,H3>; }
+impl HexMul<H8> for H5 { type Output = Byte<H2,H8>; }
+impl HexMul<H9> for H5 { type Output = Byte<H2,H13>; }
+impl HexMul<H10> for H5 { type Output = Byte<H3,H2>; }
+impl HexMul<H11> for H5 { type Output = Byte<H3,H7>; }
+impl HexMul<H12> for H5 { type Output = Byte<H3,H12>; }
+impl HexMul<H13> for H5 { type Output = Byte<H4,H1>; }
+impl HexMul<H14> for H5 { type Output = Byte<H4,H6>; }
+impl HexMul<H15> for H5 { type Output = Byte<H4,H11>; }
+impl HexMul<H0> for H6 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H6 { type Output = Byte<H0,H6>; }
+impl HexMul<H2> for H6 { type Output = Byte<H0,H12>; }
+impl HexMul<H3> for H6 { type Output = Byte<H1,H2>; }
+impl HexMul<H4> for H6 { type Output = Byte<H1,H8>; }
+impl HexMul<H5> for H6 { type Output = Byte<H1,H14>; }
+impl HexMul<H6> for H6 { type Output = Byte<H2,H4>; }
+impl HexMul<H7> for H6 { type Output = Byte<H2,H10>; }
+impl HexMul<H8> for H6 { type Output = Byte<H3,H0>; }
+impl HexMul<H9> for H6 { type Output = Byte<H3,H6>; }
+impl HexMul<H10> for H6 { type Output = Byte<H3,H12>; }
+impl HexMul<H11> for H6 { type Output = Byte<H4,H2>; }
+impl HexMul<H12> for H6 { type Output = Byte<H4,H8>; }
+impl HexMul<H13> for H6 { type Output = Byte<H4,H14>; }
+impl HexMul<H14> for H6 { type Output = Byte<H5,H4>; }
+impl HexMul<H15> for H6 { type Output = Byte<H5,H10>; }
+impl HexMul<H0> for H7 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H7 { type Output = Byte<H0,H7>; }
+impl HexMul<H2> for H7 { type Output = Byte<H0,H14>; }
+impl HexMul<H3> for H7 { type Output = Byte<H1,H5>; }
+impl HexMul<H4> for H7 { type Output = Byte<H1,H12>; }
+impl HexMul<H5> for H7 { type Output = Byte<H2,H3>; }
+impl HexMul<H6> for H7 { type Output = Byte<H2,H10>; }
+impl HexMul<H7> for H7 { type Output = Byte<H3,H1>; }
+impl HexMul<H8> for H7 { type Output = Byte<H3,H8>; }
+impl HexMul<H9> for H7 { type Output = Byte<H3,H15>; }
+impl HexMul<H10> for H7 { type Output = Byte<H4,H6>; }
+impl HexMul<H11> for H7 { type Output = Byte<H4,H13>; }
+impl HexMul<H12> for H7 { type Output = Byte<H5,H4>; }
+impl HexMul<H13> for H7 { type Output = Byte<H5,H11>; }
+impl HexMul<H14> for H7 { type Output = Byte<H6,H2>; }
+impl HexMul<H15> for H7 { type Output = Byte<H6,H9>; }
+impl HexMul<H0> for H8 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H8 { type Output = Byte<H0,H8>; }
+impl HexMul<H2> for H8 { type Output = Byte<H1,H0>; }
+impl HexMul<H3> for H8 { type Output = Byte<H1,H8>; }
+impl HexMul<H4> for H8 { type Output = Byte<H2,H0>; }
+impl HexMul<H5> for H8 { type Output = Byte<H2,H8>; }
+impl HexMul<H6> for H8 { type Output = Byte<H3,H0>; }
+impl HexMul<H7> for H8 { type Output = Byte<H3,H8>; }
+impl HexMul<H8> for H8 { type Output = Byte<H4,H0>; }
+impl HexMul<H9> for H8 { type Output = Byte<H4,H8>; }
+impl HexMul<H10> for H8 { type Output = Byte<H5,H0>; }
+impl HexMul<H11> for H8 { type Output = Byte<H5,H8>; }
+impl HexMul<H12> for H8 { type Output = Byte<H6,H0>; }
+impl HexMul<H13> for H8 { type Output = Byte<H6,H8>; }
+impl HexMul<H14> for H8 { type Output = Byte<H7,H0>; }
+impl HexMul<H15> for H8 { type Output = Byte<H7,H8>; }
+impl HexMul<H0> for H9 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H9 { type Output = Byte<H0,H9>; }
+impl HexMul<H2> for H9 { type Output = Byte<H1,H2>; }
+impl HexMul<H3> for H9 { type Output = Byte<H1,H11>; }
+impl HexMul<H4> for H9 { type Output = Byte<H2,H4>; }
+impl HexMul<H5> for H9 { type Output = Byte<H2,H13>; }
+impl HexMul<H6> for H9 { type Output = Byte<H3,H6>; }
+impl HexMul<H7> for H9 { type Output = Byte<H3,H15>; }
+impl HexMul<H8> for H9 { type Output = Byte<H4,H8>; }
+impl HexMul<H9> for H9 { type Output = Byte<H5,H1>; }
+impl HexMul<H10> for H9 { type Output = Byte<H5,H10>; }
+impl HexMul<H11> for H9 { type Output = Byte<H6,H3>; }
+impl HexMul<H12> for H9 { type Output = Byte<H6,H12>; }
+impl HexMul<H13> for H9 { type Output = Byte<H7,H5>; }
+impl HexMul<H14> for H9 { type Output = Byte<H7,H14>; }
+impl HexMul<H15> for H9 { type Output = Byte<H8,H7>; }
+impl HexMul<H0> for H10 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H10 { type Output = Byte<H0,H10>; }
+impl HexMul<H2> for H10 { type Output = Byte<H1,H4>; }
+impl HexMul<H3> for H10 { type Output = Byte<H1,H14>; }
+impl HexMul<H4> for H10 { type Output = Byte<H2,H8>; }
+impl HexMul<H5> for H10 { type Output = Byte<H3,H2>; }
+impl HexMul<H6> for H10 { type Output = Byte<H3,H12>; }
+impl HexMul<H7> for H10 { type Output = Byte<H4,H6>; }
+impl HexMul<H8> for H10 { type Output = Byte<H5,H0>; }
+impl HexMul<H9> for H10 { type Output = Byte<H5,H10>; }
+impl HexMul<H10> for H10 { type Output = Byte<H6,H4>; }
+impl HexMul<H11> for H10 { type Output = Byte<H6,H14>; }
+impl HexMul<H12> for H10 { type Output = Byte<H7,H8>; }
+impl HexMul<H13> for H10 { type Output = Byte<H8,H2>; }
+impl HexMul<H14> for H10 { type Output = Byte<H8,H12>; }
+impl HexMul<H15> for H10 { type Output = Byte<H9,H6>; }
+impl HexMul<H0> for H11 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H11 { type Output = Byte<H0,H11>; }
+impl HexMul<H2> for H11 { type Output = Byte<H1,H6>; }
+impl HexMul<H3> for H11 { type Output = Byte<H2,H1>; }
+impl HexMul<H4> for H11 { type Output = Byte<H2,H12>; }
+impl HexMul<H5> for H11 { type Output = Byte<H3,H7>; }
+impl HexMul<H6> for H11 { type Output = Byte<H4,H2>; }
+impl HexMul<H7> for H11 { type Output = Byte<H4,H13>; }
+impl HexMul<H8> for H11 { type Output = Byte<H5,H8>; }
+impl HexMul<H9> for H11 { type Output = Byte<H6,H3>; }
+impl HexMul<H10> for H11 { type Output = Byte<H6,H14>; }
+impl HexMul<H11> for H11 { type Output = Byte<H7,H9>; }
+impl HexMul<H12> for H11 { type Output = Byte<H8,H4>; }
+impl HexMul<H13> for H11 { type Output = Byte<H8,H15>; }
+impl HexMul<H14> for H11 { type Output = Byte<H9,H10>; }
+impl HexMul<H15> for H11 { type Output = Byte<H10,H5>; }
+impl HexMul<H0> for H12 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H12 { type Output = Byte<H0,H12>; }
+impl HexMul<H2> for H12 { type Output = Byte<H1,H8>; }
+impl HexMul<H3> for H12 { type Output = Byte<H2,H4>; }
+impl HexMul<H4> for H12 { type Output = Byte<H3,H0>; }
+impl HexMul<H5> for H12 { type Output = Byte<H3,H12>; }
+impl HexMul<H6> for H12 { type Output = Byte<H4,H8>; }
+impl HexMul<H7> for H12 { type Output = Byte<H5,H4>; }
+impl HexMul<H8> for H12 { type Output = Byte<H6,H0>; }
+impl HexMul<H9> for H12 { type Output = Byte<H6,H12>; }
+impl HexMul<H10> for H12 { type Output = Byte<H7,H8>; }
+impl HexMul<H11> for H12 { type Output = Byte<H8,H4>; }
+impl HexMul<H12> for H12 { type Output = Byte<H9,H0>; }
+impl HexMul<H13> for H12 { type Output = Byte<H9,H12>; }
+impl HexMul<H14> for H12 { type Output = Byte<H10,H8>; }
+impl HexMul<H15> for H12 { type Output = Byte<H11,H4>; }
+impl HexMul<H0> for H13 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H13 { type Output = Byte<H0,H13>; }
+impl HexMul<H2> for H13 { type Output = Byte<H1,H10>; }
+impl HexMul<H3> for H13 { type Output = Byte<H2,H7>; }
+impl HexMul<H4> for H13 { type Output = Byte<H3,H4>; }
+impl HexMul<H5> for H13 { type Output = Byte<H4,H1>; }
+impl HexMul<H6> for H13 { type Output = Byte<H4,H14>; }
+impl HexMul<H7> for H13 { type Output = Byte<H5,H11>; }
+impl HexMul<H8> for H13 { type Output = Byte<H6,H8>; }
+impl HexMul<H9> for H13 { type Output = Byte<H7,H5>; }
+impl HexMul<H10> for H13 { type Output = Byte<H8,H2>; }
+impl HexMul<H11> for H13 { type Output = Byte<H8,H15>; }
+impl HexMul<H12> for H13 { type Output = Byte<H9,H12>; }
+impl HexMul<H13> for H13 { type Output = Byte<H10,H9>; }
+impl HexMul<H14> for H13 { type Output = Byte<H11,H6>; }
+impl HexMul<H15> for H13 { type Output = Byte<H12,H3>; }
+impl HexMul<H0> for H14 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H14 { type Output = Byte<H0,H14>; }
+impl HexMul<H2> for H14 { type Output = Byte<H1,H12>; }
+impl HexMul<H3> for H14 { type Output = Byte<H2,H10>; }
+impl HexMul<H4> for H14 { type Output = Byte<H3,H8>; }
+impl HexMul<H5> for H14 { type Output = Byte<H4,H6>; }
+impl HexMul<H6> for H14 { type Output = Byte<H5,H4>; }
+impl HexMul<H7> for H14 { type Output = Byte<H6,H2>; }
+impl HexMul<H8> for H14 { type Output = Byte<H7,H0>; }
+impl HexMul<H9> for H14 { type Output = Byte<H7,H14>; }
+impl HexMul<H10> for H14 { type Output = Byte<H8,H12>; }
+impl HexMul<H11> for H14 { type Output = Byte<H9,H10>; }
+impl HexMul<H12> for H14 { type Output = Byte<H10,H8>; }
+impl HexMul<H13> for H14 { type Output = Byte<H11,H6>; }
+impl HexMul<H14> for H14 { type Output = Byte<H12,H4>; }
+impl HexMul<H15> for H14 { type Output = Byte<H13,H2>; }
+impl HexMul<H0> for H15 { type Output = Byte<H0,H0>; }
+impl HexMul<H1> for H15 { type Output = Byte<H0,H15>; }
+impl HexMul<H2> for H15 { type Output = Byte<H1,H14>; }
+impl HexMul<H3> for H15 { type Output = Byte<H2,H13>; }
+impl HexMul<H4> for H15 { type Output = Byte<H3,H12>; }
+impl HexMul<H5> for H15 { type Output = Byte<H4,H11>; }
+impl HexMul<H6> for H15 { type Output = Byte<H5,H10>; }
+impl HexMul<H7> for H15 { type Output = Byte<H6,H9>; }
+impl HexMul<H8> for H15 { type Output = Byte<H7,H8>; }
+impl HexMul<H9> for H15 { type Output = Byte<H8,H7>; }
+impl HexMul<H10> for H15 { type Output = Byte<H9,H6>; }
+impl HexMul<H11> for H15 { type Output = Byte<H10,H5>; }
+impl HexMul<H12> for H15 { type Output = Byte<H11,H4>; }
+impl HexMul<H13> for H15 { type Output = Byte<H12,H3>; }
+impl HexMul<H14> for H15 { type Output = Byte<H13,H2>; }
+impl HexMul<H15> for H15 { type Output = Byte<H14,H1>; }
+
+// Self + Rhs, as the carry out and the sum digit.
+pub trait HexAdd<Rhs> {
+    type Output;
+}
+impl HexAdd<H0> for H0 { type Output = (B0, H0); }
+impl HexAdd<H1> for H0 { type Output = (B0, H1); }
+impl HexAdd<H2> for H0 { type Output = (B0, H2); }
+impl HexAdd<H3> for H0 { type Output = (B0, H3); }
+impl HexAdd<H4> for H0 { type Output = (B0, H4); }
+impl HexAdd<H5> for H0 { type Output = (B0, H5); }
+impl HexAdd<H6> for H0 { type Output = (B0, H6); }
+impl HexAdd<H7> for H0 { type Output = (B0, H7); }
+impl HexAdd<H8> for H0 { type Output = (B0, H8); }
+impl HexAdd<H9> for H0 { type Output = (B0, H9); }
+impl HexAdd<H10> for H0 { type Output = (B0, H10); }
+impl HexAdd<H11> for H0 { type Output = (B0, H11); }
+impl HexAdd<H12> for H0 { type Output = (B0, H12); }
+impl HexAdd<H13> for H0 { type Output = (B0, H13); }
+impl HexAdd<H14> for H0 { type Output = (B0, H14); }
+impl HexAdd<H15> for H0 { type Output = (B0, H15); }
+impl HexAdd<H0> for H1 { type Output = (B0, H1); }
+impl HexAdd<H1> for H1 { type Output = (B0, H2); }
+impl HexAdd<H2> for H1 { type Output = (B0, H3); }
+impl HexAdd<H3> for H1 { type Output = (B0, H4); }
+impl HexAdd<H4> for H1 { type Output = (B0, H5); }
+impl HexAdd<H5> for H1 { type Output = (B0, H6); }
+impl HexAdd<H6> for H1 { type Output = (B0, H7); }
+impl HexAdd<H7> for H1 { type Output = (B0, H8); }
+impl HexAdd<H8> for H1 { type Output = (B0, H9); }
+impl HexAdd<H9> for H1 { type Output = (B0, H10); }
+impl HexAdd<H10> for H1 { type Output = (B0, H11); }
+impl HexAdd<H11> for H1 { type Output = (B0, H12); }
+impl HexAdd<H12> for H1 { type Output = (B0, H13); }
+impl HexAdd<H13> for H1 { type Output = (B0, H14); }
+impl HexAdd<H14> for H1 { type Output = (B0, H15); }
+impl HexAdd<H15> for H1 { type Output = (B1, H0); }
+impl HexAdd<H0> for H2 { type Output = (B0, H2); }
+impl HexAdd<H1> for H2 { type Output = (B0, H3); }
+impl HexAdd<H2> for H2 { type Output = (B0, H4); }
+impl HexAdd<H3> for H2 { type Output = (B0, H5); }
+impl HexAdd<H4> for H2 { type Output = (B0, H6); }
+impl HexAdd<H5> for H2 { type Output = (B0, H7); }
+impl HexAdd<H6> for H2 { type Output = (B0, H8); }
+impl HexAdd<H7> for H2 { type Output = (B0, H9); }
+impl HexAdd<H8> for H2 { type Output = (B0, H10); }
+impl HexAdd<H9> for H2 { type Output = (B0, H11); }
+impl HexAdd<H10> for H2 { type Output = (B0, H12); }
+impl HexAdd<H11> for H2 { type Output = (B0, H13); }
+impl HexAdd<H12> for H2 { type Output = (B0, H14); }
+impl HexAdd<H13> for H2 { type Output = (B0, H15); }
+impl HexAdd<H14> for H2 { type Output = (B1, H0); }
+impl HexAdd<H15> for H2 { type Output = (B1, H1); }
+impl HexAdd<H0> for H3 { type Output = (B0, H3); }
+impl HexAdd<H1> for H3 { type Output = (B0, H4); }
+impl HexAdd<H2> for H3 { type Output = (B0, H5); }
+impl HexAdd<H3> for H3 { type Output = (B0, H6); }
+impl HexAdd<H4> for H3 { type Output = (B0, H7); }
+impl HexAdd<H5> for H3 { type Output = (B0, H8); }
+impl HexAdd<H6> for H3 { type Output = (B0, H9); }
+impl HexAdd<H7> for H3 { type Output = (B0, H10); }
+impl HexAdd<H8> for H3 { type Output = (B0, H11); }
+impl HexAdd<H9> for H3 { type Output = (B0, H12); }
+impl HexAdd<H10> for H3 { type Output = (B0, H13); }
+impl HexAdd<H11> for H3 { type Output = (B0, H14); }
+impl HexAdd<H12> for H3 { type Output = (B0, H15); }
+impl HexAdd<H13> for H3 { type Output = (B1, H0); }
+impl HexAdd<H14> for H3 { type Output = (B1, H1); }
+impl HexAdd<H15> for H3 { type Output = (B1, H2); }
+impl HexAdd<H0> for H4 { type Output = (B0, H4); }
+impl HexAdd<H1> for H4 { type Output = (B0, H5); }
+impl HexAdd<H2> for H4 { type Output = (B0, H6); }
+impl HexAdd<H3> for H4 { type Output = (B0, H7); }
+impl HexAdd<H4> for H4 { type Output = (B0, H8); }
+impl HexAdd<H5> for H4 { type Output = (B0, H9); }
+impl HexAdd<H6> for H4 { type Output = (B0, H10); }
+impl HexAdd<H7> for H4 { type Output = (B0, H11); }
+impl HexAdd<H8> for H4 { type Output = (B0, H12); }
+impl HexAdd<H9> for H4 { type Output = (B0, H13); }
+impl HexAdd<H10> for H4 { type Output = (B0, H14); }
+impl HexAdd<H11> for H4 { type Output = (B0, H15); }
+impl HexAdd<H12> for H4 { type Output = (B1, H0); }
+impl HexAdd<H13> for H4 { type Output = (B1, H1); }
+impl HexAdd<H14> for H4 { type Output = (B1, H2); }
+impl HexAdd<H15> for H4 { type Output = (B1, H3); }
+impl HexAdd<H0> for H5 { type Output = (B0, H5); }
+impl HexAdd<H1> for H5 { type Output = (B0, H6); }
+impl HexAdd<H2> for H5 { type Output = (B0, H7); }
+impl HexAdd<H3> for H5 { type Output = (B0, H8); }
+impl HexAdd<H4> for H5 { type Output = (B0, H9); }
+impl HexAdd<H5> for H5 { type Output = (B0, H10); }
+impl HexAdd<H6> for H5 { type Output = (B0, H11); }
+impl HexAdd<H7> for H5 { type Output = (B0, H12); }
+impl HexAdd<H8> for H5 { type Output = (B0, H13); }
+impl HexAdd<H9> for H5 { type Output = (B0, H14); }
+impl HexAdd<H10> for H5 { type Output = (B0, H15); }
+impl HexAdd<H11> for H5 { type Output = (B1, H0); }
+impl HexAdd<H12> for H5 { type Output = (B1, H1); }
+impl HexAdd<H13> for H5 { type Output = (B1, H2); }
+impl HexAdd<H14> for H5 { type Output = (B1, H3); }
+impl HexAdd<H15> for H5 { type Output = (B1, H4); }
+impl HexAdd<H0> for H6 { type Output = (B0, H6); }
+impl HexAdd<H1> for H6 { type Output = (B0, H7); }
+impl HexAdd<H2> for H6 { type Output = (B0, H8); }
+impl HexAdd<H3> for H6 { type Output = (B0, H9); }
+impl HexAdd<H4> for H6 { type Output = (B0, H10); }
+impl HexAdd<H5> for H6 { type Output = (B0, H11); }
+impl HexAdd<H6> for H6 { type Output = (B0, H12); }
+impl HexAdd<H7> for H6 { type Output = (B0, H13); }
+impl HexAdd<H8> for H6 { type Output = (B0, H14); }
+impl HexAdd<H9> for H6 { type Output = (B0, H15); }
+impl HexAdd<H10> for H6 { type Output = (B1, H0); }
+impl HexAdd<H11> for H6 { type Output = (B1, H1); }
+impl HexAdd<H12> for H6 { type Output = (B1, H2); }
+impl HexAdd<H13> for H6 { type Output = (B1, H3); }
+impl HexAdd<H14> for H6 { type Output = (B1, H4); }
+impl HexAdd<H15> for H6 { type Output = (B1, H5); }
+impl HexAdd<H0> for H7 { type Output = (B0, H7); }
+impl HexAdd<H1> for H7 { type Output = (B0, H8); }
+impl HexAdd<H2> for H7 { type Output = (B0, H9); }
+impl HexAdd<H3> for H7 { type Output = (B0, H10); }
+impl HexAdd<H4> for H7 { type Output = (B0, H11); }
+impl HexAdd<H5> for H7 { type Output = (B0, H12); }
+impl HexAdd<H6> for H7 { type Output = (B0, H13); }
+impl HexAdd<H7> for H7 { type Output = (B0, H14); }
+impl HexAdd<H8> for H7 { type Output = (B0, H15); }
+impl HexAdd<H9> for H7 { type Output = (B1, H0); }
+impl HexAdd<H10> for H7 { type Output = (B1, H1); }
+impl HexAdd<H11> for H7 { type Output = (B1, H2); }
+impl HexAdd<H12> for H7 { type Output = (B1, H3); }
+impl HexAdd<H13> for H7 { type Output = (B1, H4); }
+impl HexAdd<H14> for H7 { type Output = (B1, H5); }
+impl HexAdd<H15> for H7 { type Output = (B1, H6); }
+impl HexAdd<H0> for H8 { type Output = (B0, H8); }
+impl HexAdd<H1> for H8 { type Output = (B0, H9); }
+impl HexAdd<H2> for H8 { type Output = (B0, H10); }
+impl HexAdd<H3> for H8 { type Output = (B0, H11); }
+impl HexAdd<H4> for H8 { type Output = (B0, H12); }
+impl HexAdd<H5> for H8 { type Output = (B0, H13); }
+impl HexAdd<H6> for H8 { type Output = (B0, H14); }
+impl HexAdd<H7> for H8 { type Output = (B0, H15); }
+impl HexAdd<H8> for H8 { type Output = (B1, H0); }
+impl HexAdd<H9> for H8 { type Output = (B1, H1); }
+impl HexAdd<H10> for H8 { type Output = (B1, H2); }
+impl HexAdd<H11> for H8 { type Output = (B1, H3); }
+impl HexAdd<H12> for H8 { type Output = (B1, H4); }
+impl HexAdd<H13> for H8 { type Output = (B1, H5); }
+impl HexAdd<H14> for H8 { type Output = (B1, H6); }
+impl HexAdd<H15> for H8 { type Output = (B1, H7); }
+impl HexAdd<H0> for H9 { type Output = (B0, H9); }
+impl HexAdd<H1> for H9 { type Output = (B0, H10); }
+impl HexAdd<H2> for H9 { type Output = (B0, H11); }
+impl HexAdd<H3> for H9 { type Output = (B0, H12); }
+impl HexAdd<H4> for H9 { type Output = (B0, H13); }
+impl HexAdd<H5> for H9 { type Output = (B0, H14); }
+impl HexAdd<H6> for H9 { type Output = (B0, H15); }
+impl HexAdd<H7> for H9 { type Output = (B1, H0); }
+impl HexAdd<H8> for H9 { type Output = (B1, H1); }
+impl HexAdd<H9> for H9 { type Output = (B1, H2); }
+impl HexAdd<H10> for H9 { type Output = (B1, H3); }
+impl HexAdd<H11> for H9 { type Output = (B1, H4); }
+impl HexAdd<H12> for H9 { type Output = (B1, H5); }
+impl HexAdd<H13> for H9 { type Output = (B1, H6); }
+impl HexAdd<H14> for H9 { type Output = (B1, H7); }
+impl HexAdd<H15> for H9 { type Output = (B1, H8); }
+impl HexAdd<H0> for H10 { type Output = (B0, H10); }
+impl HexAdd<H1> for H10 { type Output = (B0, H11); }
+impl HexAdd<H2> for H10 { type Output = (B0, H12); }
+impl HexAdd<H3> for H10 { type Output = (B0, H13); }
+impl HexAdd<H4> for H10 { type Output = (B0, H14); }
+impl HexAdd<H5> for H10 { type Output = (B0, H15); }
+impl HexAdd<H6> for H10 { type Output = (B1, H0); }
+impl HexAdd<H7> for H10 { type Output = (B1, H1); }
+impl HexAdd<H8> for H10 { type Output = (B1, H2); }
+impl HexAdd<H9> for H10 { type Output = (B1, H3); }
+impl HexAdd<H10> for H10 { type Output = (B1, H4); }
+impl HexAdd<H11> for H10 { type Output = (B1, H5); }
+impl HexAdd<H12> for H10 { type Output = (B1, H6); }
+impl HexAdd<H13> for H10 { type Output = (B1, H7); }
+impl HexAdd<H14> for H10 { type Output = (B1, H8); }
+impl HexAdd<H15> for H10 { type Output = (B1, H9); }
+impl HexAdd<H0> for H11 { type Output = (B0, H11); }
+impl HexAdd<H1> for H11 { type Output = (B0, H12); }
+impl HexAdd<H2> for H11 { type Output = (B0, H13); }
+impl HexAdd<H3> for H11 { type Output = (B0, H14); }
+impl HexAdd<H4> for H11 { type Output = (B0, H15); }
+impl HexAdd<H5> for H11 { type Output = (B1, H0); }
+impl HexAdd<H6> for H11 { type Output = (B1, H1); }
+impl HexAdd<H7> for H11 { type Output = (B1, H2); }
+impl HexAdd<H8> for H11 { type Output = (B1, H3); }
+impl HexAdd<H9> for H11 { type Output = (B1, H4); }
+impl HexAdd<H10> for H11 { type Output = (B1, H5); }
+impl HexAdd<H11> for H11 { type Output = (B1, H6); }
+impl HexAdd<H12> for H11 { type Output = (B1, H7); }
+impl HexAdd<H13> for H11 { type Output = (B1, H8); }
+impl HexAdd<H14> for H11 { type Output = (B1, H9); }
+impl HexAdd<H15> for H11 { type Output = (B1, H10); }
+impl HexAdd<H0> for H12 { type Output = (B0, H12); }
+impl HexAdd<H1> for H12 { type Output = (B0, H13); }
+impl HexAdd<H2> for H12 { type Output = (B0, H14); }
+impl HexAdd<H3> for H12 { type Output = (B0, H15); }
+impl HexAdd<H4> for H12 { type Output = (B1, H0); }
+impl HexAdd<H5> for H12 { type Output = (B1, H1); }
+impl HexAdd<H6> for H12 { type Output = (B1, H2); }
+impl HexAdd<H7> for H12 { type Output = (B1, H3); }
+impl HexAdd<H8> for H12 { type Output = (B1, H4); }
+impl HexAdd<H9> for H12 { type Output = (B1, H5); }
+impl HexAdd<H10> for H12 { type Output = (B1, H6); }
+impl HexAdd<H11> for H12 { type Output = (B1, H7); }
+impl HexAdd<H12> for H12 { type Output = (B1, H8); }
+impl HexAdd<H13> for H12 { type Output = (B1, H9); }
+impl HexAdd<H14> for H12 { type Output = (B1, H10); }
+impl HexAdd<H15> for H12 { type Output = (B1, H11); }
+impl HexAdd<H0> for H13 { type Output = (B0, H13); }
+impl HexAdd<H1> for H13 { type Output = (B0, H14); }
+impl HexAdd<H2> for H13 { type Output = (B0, H15); }
+impl HexAdd<H3> for H13 { type Output = (B1, H0); }
+impl HexAdd<H4> for H13 { type Output = (B1, H1); }
+impl HexAdd<H5> for H13 { type Output = (B1, H2); }
+impl HexAdd<H6> for H13 { type Output = (B1, H3); }
+impl HexAdd<H7> for H13 { type Output = (B1, H4); }
+impl HexAdd<H8> for H13 { type Output = (B1, H5); }
+impl HexAdd<H9> for H13 { type Output = (B1, H6); }
+impl HexAdd<H10> for H13 { type Output = (B1, H7); }
+impl HexAdd<H11> for H13 { type Output = (B1, H8); }
+impl HexAdd<H12> for H13 { type Output = (B1, H9); }
+impl HexAdd<H13> for H13 { type Output = (B1, H10); }
+impl HexAdd<H14> for H13 { type Output = (B1, H11); }
+impl HexAdd<H15> for H13 { type Output = (B1, H12); }
+impl HexAdd<H0> for H14 { type Output = (B0, H14); }
+impl HexAdd<H1> for H14 { type Output = (B0, H15); }
+impl HexAdd<H2> for H14 { type Output = (B1, H0); }
+impl HexAdd<H3> for H14 { type Output = (B1, H1); }
+impl HexAdd<H4> for H14 { type Output = (B1, H2); }
+impl HexAdd<H5> for H14 { type Output = (B1, H3); }
+impl HexAdd<H6> for H14 { type Output = (B1, H4); }
+impl HexAdd<H7> for H14 { type Output = (B1, H5); }
+impl HexAdd<H8> for H14 { type Output = (B1, H6); }
+impl HexAdd<H9> for H14 { type Output = (B1, H7); }
+impl HexAdd<H10> for H14 { type Output = (B1, H8); }
+impl HexAdd<H11> for H14 { type Output = (B1, H9); }
+impl HexAdd<H12> for H14 { type Output = (B1, H10); }
+impl HexAdd<H13> for H14 { type Output = (B1, H11); }
+impl HexAdd<H14> for H14 { type Output = (B1, H12); }
+impl HexAdd<H15> for H14 { type Output = (B1, H13); }
+impl HexAdd<H0> for H15 { type Output = (B0, H15); }
+impl HexAdd<H1> for H15 { type Output = (B1, H0); }
+impl HexAdd<H2> for H15 { type Output = (B1, H1); }
+impl HexAdd<H3> for H15 { type Output = (B1, H2); }
+impl HexAdd<H4> for H15 { type Output = (B1, H3); }
+impl HexAdd<H5> for H15 { type Output = (B1, H4); }
+impl HexAdd<H6> for H15 { type Output = (B1, H5); }
+impl HexAdd<H7> for H15 { type Output = (B1, H6); }
+impl HexAdd<H8> for H15 { type Output = (B1, H7); }
+impl HexAdd<H9> for H15 { type Output = (B1, H8); }
+impl HexAdd<H10> for H15 { type Output = (B1, H9); }
+impl HexAdd<H11> for H15 { type Output = (B1, H10); }
+impl HexAdd<H12> for H15 { type Output = (B1, H11); }
+impl HexAdd<H13> for H15 { type Output = (B1, H12); }
+impl HexAdd<H14> for H15 { type Output = (B1, H13); }
+impl HexAdd<H15> for H15 { type Output = (B1, H14); }
+
+// Self + Carry, which is at most 0xf.
+pub trait HexCarry<Carry> {
+    type Output;
+}
+impl HexCarry<B0> for H0 { type Output = H0; }
+impl HexCarry<B1> for H0 { type Output = H1; }
+impl HexCarry<B0> for H1 { type Output = H1; }
+impl HexCarry<B1> for H1 { type Output = H2; }
+impl HexCarry<B0> for H2 { type Output = H2; }
+impl HexCarry<B1> for H2 { type Output = H3; }
+impl HexCarry<B0> for H3 { type Output = H3; }
+impl HexCarry<B1> for H3 { type Output = H4; }
+impl HexCarry<B0> for H4 { type Output = H4; }
+impl HexCarry<B1> for H4 { type Output = H5; }
+impl HexCarry<B0> for H5 { type Output = H5; }
+impl HexCarry<B1> for H5 { type Output = H6; }
+impl HexCarry<B0> for H6 { type Output = H6; }
+impl HexCarry<B1> for H6 { type Output = H7; }
+impl HexCarry<B0> for H7 { type Output = H7; }
+impl HexCarry<B1> for H7 { type Output = H8; }
+impl HexCarry<B0> for H8 { type Output = H8; }
+impl HexCarry<B1> for H8 { type Output = H9; }
+impl HexCarry<B0> for H9 { type Output = H9; }
+impl HexCarry<B1> for H9 { type Output = H10; }
+impl HexCarry<B0> for H10 { type Output = H10; }
+impl HexCarry<B1> for H10 { type Output = H11; }
+impl HexCarry<B0> for H11 { type Output = H11; }
+impl HexCarry<B1> for H11 { type Output = H12; }
+impl HexCarry<B0> for H12 { type Output = H12; }
+impl HexCarry<B1> for H12 { type Output = H13; }
+impl HexCarry<B0> for H13 { type Output = H13; }
+impl HexCarry<B1> for H13 { type Output = H14; }
+impl HexCarry<B0> for H14 { type Output = H14; }
+impl HexCarry<B1> for H14 { type Output = H15; }
+impl HexCarry<B0> for H15 { type Output = H15; }
+
+// Self + C, where the sum is at most 0xff.
+pub trait ByteAdd<C> {
+    type Output;
+}
+
+impl<Hi,Lo,C> ByteAdd<C> for Byte<Hi,Lo>
+    where Lo: HexAdd<C>,
+          <Lo as HexAdd<C>>::Output: ByteAddCarry<Hi> {
+    type Output = <<Lo as HexAdd<C>>::Output as ByteAddCarry<Hi>>::Output;
+}
+
+// The rest of ByteAdd, where Self is the carry and the low digit.
+pub trait ByteAddCarry<Hi> {
+    type Output;
+}
+
+impl<Hi,Carry,Lo> ByteAddCarry<Hi> for (Carry, Lo) where Hi: HexCarry<Carry> {
+    type Output = Byte<<Hi as HexCarry<Carry>>::Output,Lo>;
+}
+
+// Self*D + A, which is at most 0xff.
+pub trait HexMulAdd<D,A> {
+    type Output;
+}
+
+impl<X,D,A> HexMulAdd<D,A> for X
+    where X: HexMul<D>,
+          <X as HexMul<D>>::Output: ByteAdd<A> {
+    type Output = <<X as HexMul<D>>::Output as ByteAdd<A>>::Output;
+}
+
+// Self - Rhs - Borrow, as the borrow out and the difference digit.
+pub trait HexSub<Rhs,Borrow> {
+    type Output;
+}
+impl HexSub<H0,B0> for H0 { type Output = (B0, H0); }
+impl HexSub<H0,B1> for H0 { type Output = (B1, H15); }
+impl HexSub<H1,B0> for H0 { type Output = (B1, H15); }
+impl HexSub<H1,B1> for H0 { type Output = (B1, H14); }
+impl HexSub<H2,B0> for H0 { type Output = (B1, H14); }
+impl HexSub<H2,B1> for H0 { type Output = (B1, H13); }
+impl HexSub<H3,B0> for H0 { type Output = (B1, H13); }
+impl HexSub<H3,B1> for H0 { type Output = (B1, H12); }
+impl HexSub<H4,B0> for H0 { type Output = (B1, H12); }
+impl HexSub<H4,B1> for H0 { type Output = (B1, H11); }
+impl HexSub<H5,B0> for H0 { type Output = (B1, H11); }
+impl HexSub<H5,B1> for H0 { type Output = (B1, H10); }
+impl HexSub<H6,B0> for H0 { type Output = (B1, H10); }
+impl HexSub<H6,B1> for H0 { type Output = (B1, H9); }
+impl HexSub<H7,B0> for H0 { type Output = (B1, H9); }
+impl HexSub<H7,B1> for H0 { type Output = (B1, H8); }
+impl HexSub<H8,B0> for H0 { type Output = (B1, H8); }
+impl HexSub<H8,B1> for H0 { type Output = (B1, H7); }
+impl HexSub<H9,B0> for H0 { type Output = (B1, H7); }
+impl HexSub<H9,B1> for H0 { type Output = (B1, H6); }
+impl HexSub<H10,B0> for H0 { type Output = (B1, H6); }
+impl HexSub<H10,B1> for H0 { type Output = (B1, H5); }
+impl HexSub<H11,B0> for H0 { type Output = (B1, H5); }
+impl HexSub<H11,B1> for H0 { type Output = (B1, H4); }
+impl HexSub<H12,B0> for H0 { type Output = (B1, H4); }
+impl HexSub<H12,B1> for H0 { type Output = (B1, H3); }
+impl HexSub<H13,B0> for H0 { type Output = (B1, H3); }
+impl HexSub<H13,B1> for H0 { type Output = (B1, H2); }
+impl HexSub<H14,B0> for H0 { type Output = (B1, H2); }
+impl HexSub<H14,B1> for H0 { type Output = (B1, H1); }
+impl HexSub<H15,B0> for H0 { type Output = (B1, H1); }
+impl HexSub<H15,B1> for H0 { type Output = (B1, H0); }
+impl HexSub<H0,B0> for H1 { type Output = (B0, H1); }
+impl HexSub<H0,B1> for H1 { type Output = (B0, H0); }
+impl HexSub<H1,B0> for H1 { type Output = (B0, H0); }
+impl HexSub<H1,B1> for H1 { type Output = (B1, H15); }
+impl HexSub<H2,B0> for H1 { type Output = (B1, H15); }
+impl HexSub<H2,B1> for H1 { type Output = (B1, H14); }
+impl HexSub<H3,B0> for H1 { type Output = (B1, H14); }
+impl HexSub<H3,B1> for H1 { type Output = (B1, H13); }
+impl HexSub<H4,B0> for H1 { type Output = (B1, H13); }
+impl HexSub<H4,B1> for H1 { type Output = (B1, H12); }
+impl HexSub<H5,B0> for H1 { type Output = (B1, H12); }
+impl HexSub<H5,B1> for H1 { type Output = (B1, H11); }
+impl HexSub<H6,B0> for H1 { type Output = (B1, H11); }
+impl HexSub<H6,B1> for H1 { type Output = (B1, H10); }
+impl HexSub<H7,B0> for H1 { type Output = (B1, H10); }
+impl HexSub<H7,B1> for H1 { type Output = (B1, H9); }
+impl HexSub<H8,B0> for H1 { type Output = (B1, H9); }
+impl HexSub<H8,B1> for H1 { type Output = (B1, H8); }
+impl HexSub<H9,B0> for H1 { type Output = (B1, H8); }
+impl HexSub<H9,B1> for H1 { type Output = (B1, H7); }
+impl HexSub<H10,B0> for H1 { type Output = (B1, H7); }
+impl HexSub<H10,B1> for H1 { type Output = (B1, H6); }
+impl HexSub<H11,B0> for H1 { type Output = (B1, H6); }
+impl HexSub<H11,B1> for H1 { type Output = (B1, H5); }
+impl HexSub<H12,B0> for H1 { type Output = (B1, H5); }
+impl HexSub<H12,B1> for H1 { type Output = (B1, H4); }
+impl HexSub<H13,B0> for H1 { type Output = (B1, H4); }
+impl HexSub<H13,B1> for H1 { type Output = (B1, H3); }
+impl HexSub<H14,B0> for H1 { type Output = (B1, H3); }
+impl HexSub<H14,B1> for H1 { type Output = (B1, H2); }
+impl HexSub<H15,B0> for H1 { type Output = (B1, H2); }
+impl HexSub<H15,B1> for H1 { type Output = (B1, H1); }
+impl HexSub<H0,B0> for H2 { type Output = (B0, H2); }
+impl HexSub<H0,B1> for H2 { type Output = (B0, H1); }
+impl HexSub<H1,B0> for H2 { type Output = (B0, H1); }
+impl HexSub<H1,B1> for H2 { type Output = (B0, H0); }
+impl HexSub<H2,B0> for H2 { type Output = (B0, H0); }
+impl HexSub<H2,B1> for H2 { type Output = (B1, H15); }
+impl HexSub<H3,B0> for H2 { type Output = (B1, H15); }
+impl HexSub<H3,B1> for H2 { type Output = (B1, H14); }
+impl HexSub<H4,B0> for H2 { type Output = (B1, H14); }
+impl HexSub<H4,B1> for H2 { type Output = (B1, H13); }
+impl HexSub<H5,B0> for H2 { type Output = (B1, H13); }
+impl HexSub<H5,B1> for H2 { type Output = (B1, H12); }
+impl HexSub<H6,B0> for H2 { type Output = (B1, H12); }
+impl HexSub<H6,B1> for H2 { type Output = (B1, H11); }
+impl HexSub<H7,B0> for H2 { type Output = (B1, H11); }
+impl HexSub<H7,B1> for H2 { type Output = (B1, H10); }
+impl HexSub<H8,B0> for H2 { type Output = (B1, H10); }
+impl HexSub<H8,B1> for H2 { type Output = (B1, H9); }
+impl HexSub<H9,B0> for H2 { type Output = (B1, H9); }
+impl HexSub<H9,B1> for H2 { type Output = (B1, H8); }
+impl HexSub<H10,B0> for H2 { type Output = (B1, H8); }
+impl HexSub<H10,B1> for H2 { type Output = (B1, H7); }
+impl HexSub<H11,B0> for H2 { type Output = (B1, H7); }
+impl HexSub<H11,B1> for H2 { type Output = (B1, H6); }
+impl HexSub<H12,B0> for H2 { type Output = (B1, H6); }
+impl HexSub<H12,B1> for H2 { type Output = (B1, H5); }
+impl HexSub<H13,B0> for H2 { type Output = (B1, H5); }
+impl HexSub<H13,B1> for H2 { type Output = (B1, H4); }
+impl HexSub<H14,B0> for H2 { type Output = (B1, H4); }
+impl HexSub<H14,B1> for H2 { type Output = (B1, H3); }
+impl HexSub<H15,B0> for H2 { type Output = (B1, H3); }
+impl HexSub<H15,B1> for H2 { type Output = (B1, H2); }
+impl HexSub<H0,B0> for H3 { type Output = (B0, H3); }
+impl HexSub<H0,B1> for H3 { type Output = (B0, H2); }
+impl HexSub<H1,B0> for H3 { type Output = (B0, H2); }
+impl HexSub<H1,B1> for H3 { type Output = (B0, H1); }
+impl HexSub<H2,B0> for H3 { type Output = (B0, H1); }
+impl HexSub<H2,B1> for H3 { type Output = (B0, H0); }
+impl HexSub<H3,B0> for H3 { type Output = (B0, H0); }
+impl HexSub<H3,B1> for H3 { type Output = (B1, H15); }
+impl HexSub<H4,B0> for H3 { type Output = (B1, H15); }
+impl HexSub<H4,B1> for H3 { type Output = (B1, H14); }
+impl HexSub<H5,B0> for H3 { type Output = (B1, H14); }
+impl HexSub<H5,B1> for H3 { type Output = (B1, H13); }
+impl HexSub<H6,B0> for H3 { type Output = (B1, H13); }
+impl HexSub<H6,B1> for H3 { type Output = (B1, H12); }
+impl HexSub<H7,B0> for H3 { type Output = (B1, H12); }
+impl HexSub<H7,B1> for H3 { type Output = (B1, H11); }
+impl HexSub<H8,B0> for H3 { type Output = (B1, H11); }
+impl HexSub<H8,B1> for H3 { type Output = (B1, H10); }
+impl HexSub<H9,B0> for H3 { type Output = (B1, H10); }
+impl HexSub<H9,B1> for H3 { type Output = (B1, H9); }
+impl HexSub<H10,B0> for H3 { type Output = (B1, H9); }
+impl HexSub<H10,B1> for H3 { type Output = (B1, H8); }
+impl HexSub<H11,B0> for H3 { type Output = (B1, H8); }
+impl HexSub<H11,B1> for H3 { type Output = (B1, H7); }
+impl HexSub<H12,B0> for H3 { type Output = (B1, H7); }
+impl HexSub<H12,B1> for H3 { type Output = (B1, H6); }
+impl HexSub<H13,B0> for H3 { type Output = (B1, H6); }
+impl HexSub<H13,B1> for H3 { type Output = (B1, H5); }
+impl HexSub<H14,B0> for H3 { type Output = (B1, H5); }
+impl HexSub<H14,B1> for H3 { type Output = (B1, H4); }
+impl HexSub<H15,B0> for H3 { type Output = (B1, H4); }
+impl HexSub<H15,B1> for H3 { type Output = (B1, H3); }
+impl HexSub<H0,B0> for H4 { type Output = (B0, H4); }
+impl HexSub<H0,B1> for H4 { type Output = (B0, H3); }
+impl HexSub<H1,B0> for H4 { type Output = (B0, H3); }
+impl HexSub<H1,B1> for H4 { type Output = (B0, H2); }
+impl HexSub<H2,B0> for H4 { type Output = (B0, H2); }
+impl HexSub<H2,B1> for H4 { type Output = (B0, H1); }
+impl HexSub<H3,B0> for H4 { type Output = (B0, H1); }
+impl HexSub<H3,B1> for H4 { type Output = (B0, H0); }
+impl HexSub<H4,B0> for H4 { type Output = (B0, H0); }
+impl HexSub<H4,B1> for H4 { type Output = (B1, H15); }
+impl HexSub<H5,B0> for H4 { type Output = (B1, H15); }
+impl HexSub<H5,B1> for H4 { type Output = (B1, H14); }
+impl HexSub<H6,B0> for H4 { type Output = (B1, H14); }
+impl HexSub<H6,B1> for H4 { type Output = (B1, H13); }
+impl HexSub<H7,B0> for H4 { type Output = (B1, H13); }
+impl HexSub<H7,B1> for H4 { type Output = (B1, H12); }
+impl HexSub<H8,B0> for H4 { type Output = (B1, H12); }
+impl HexSub<H8,B1> for H4 { type Output = (B1, H11); }
+impl HexSub<H9,B0> for H4 { type Output = (B1, H11); }
+impl HexSub<H9,B1> for H4 { type Output = (B1, H10); }
+impl HexSub<H10,B0> for H4 { type Output = (B1, H10); }
+impl HexSub<H10,B1> for H4 { type Output = (B1, H9); }
+impl HexSub<H11,B0> for H4 { type Output = (B1, H9); }
+impl HexSub<H11,B1> for H4 { type Output = (B1, H8); }
+impl HexSub<H12,B0> for H4 { type Output = (B1, H8); }
+impl HexSub<H12,B1> for H4 { type Output = (B1, H7); }
+impl HexSub<H13,B0> for H4 { type Output = (B1, H7); }
+impl HexSub<H13,B1> for H4 { type Output = (B1, H6); }
+impl HexSub<H14,B0> for H4 { type Output = (B1, H6); }
+impl HexSub<H14,B1> for H4 { type Output = (B1, H5); }
+impl HexSub<H15,B0> for H4 { type Output = (B1, H5); }
+impl HexSub<H15,B1> for H4 { type Output = (B1, H4); }
+impl HexSub<H0,B0> for H5 { type Output = (B0, H5); }
+impl HexSub<H0,B1> for H5 { type Output = (B0, H4); }
+impl HexSub<H1,B0> for H5 { type Output = (B0, H4); }
+impl HexSub<H1,B1> for H5 { type Output = (B0, H3); }
+impl HexSub<H2,B0> for H5 { type Output = (B0, H3); }
+impl HexSub<H2,B1> for H5 { type Output = (B0, H2); }
+impl HexSub<H3,B0> for H5 { type Output = (B0, H2); }
+impl HexSub<H3,B1> for H5 { type Output = (B0, H1); }
+impl HexSub<H4,B0> for H5 { type Output = (B0, H1); }
+impl HexSub<H4,B1> for H5 { type Output = (B0, H0); }
+impl HexSub<H5,B0> for H5 { type Output = (B0, H0); }
+impl HexSub<H5,B1> for H5 { type Output = (B1, H15); }
+impl HexSub<H6,B0> for H5 { type Output = (B1, H15); }
+impl HexSub<H6,B1> for H5 { type Output = (B1, H14); }
+impl HexSub<H7,B0> for H5 { type Output = (B1, H14); }
+impl HexSub<H7,B1> for H5 { type Output = (B1, H13); }
+impl HexSub<H8,B0> for H5 { type Output = (B1, H13); }
+impl HexSub<H8,B1> for H5 { type Output = (B1, H12); }
+impl HexSub<H9,B0> for H5 { type Output = (B1, H12); }
+impl HexSub<H9,B1> for H5 { type Output = (B1, H11); }
+impl HexSub<H10,B0> for H5 { type Output = (B1, H11); }
+impl HexSub<H10,B1> for H5 { type Output = (B1, H10); }
+impl HexSub<H11,B0> for H5 { type Output = (B1, H10); }
+impl HexSub<H11,B1> for H5 { type Output = (B1, H9); }
+impl HexSub<H12,B0> for H5 { type Output = (B1, H9); }
+impl HexSub<H12,B1> for H5 { type Output = (B1, H8); }
+impl HexSub<H13,B0> for H5 { type Output = (B1, H8); }
+impl HexSub<H13,B1> for H5 { type Output = (B1, H7); }
+impl HexSub<H14,B0> for H5 { type Output = (B1, H7); }
+impl HexSub<H14,B1> for H5 { type Output = (B1, H6); }
+impl HexSub<H15,B0> for H5 { type Output = (B1, H6); }
+impl HexSub<H15,B1> for H5 { type Output = (B1, H5); }
+impl HexSub<H0,B0> for H6 { type Output = (B0, H6); }
+impl HexSub<H0,B1> for H6 { type Output = (B0, H5); }
+impl HexSub<H1,B0> for H6 { type Output = (B0, H5); }
+impl HexSub<H1,B1> for H6 { type Output = (B0, H4); }
+impl HexSub<H2,B0> for H6 { type Output = (B0, H4); }
+impl HexSub<H2,B1> for H6 { type Output = (B0, H3); }
+impl HexSub<H3,B0> for H6 { type Output = (B0, H3); }
+impl HexSub<H3,B1> for H6 { type Output = (B0, H2); }
+impl HexSub<H4,B0> for H6 { type Output = (B0, H2); }
+impl HexSub<H4,B1> for H6 { type Output = (B0, H1); }
+impl HexSub<H5,B0> for H6 { type Output = (B0, H1); }
+impl HexSub<H5,B1> for H6 { type Output = (B0, H0); }
+impl HexSub<H6,B0> for H6 { type Output = (B0, H0); }
+impl HexSub<H6,B1> for H6 { type Output = (B1, H15); }
+impl HexSub<H7,B0> for H6 { type Output = (B1, H15); }
+impl HexSub<H7,B1> for H6 { type Output = (B1, H14); }
+impl HexSub<H8,B0> for H6 { type Output = (B1, H14); }
+impl HexSub<H8,B1> for H6 { type Output = (B1, H13); }
+impl HexSub<H9,B0> for H6 { type Output = (B1, H13); }
+impl HexSub<H9,B1> for H6 { type Output = (B1, H12); }
+impl HexSub<H10,B0> for H6 { type Output = (B1, H12); }
+impl HexSub<H10,B1> for H6 { type Output = (B1, H11); }
+impl HexSub<H11,B0> for H6 { type Output = (B1, H11); }
+impl HexSub<H11,B1> for H6 { type Output = (B1, H10); }
+impl HexSub<H12,B0> for H6 { type Output = (B1, H10); }
+impl HexSub<H12,B1> for H6 { type Output = (B1, H9); }
+impl HexSub<H13,B0> for H6 { type Output = (B1, H9); }
+impl HexSub<H13,B1> for H6 { type Output = (B1, H8); }
+impl HexSub<H14,B0> for H6 { type Output = (B1, H8); }
+impl HexSub<H14,B1> for H6 { type Output = (B1, H7); }
+impl HexSub<H15,B0> for H6 { type Output = (B1, H7); }
+impl HexSub<H15,B1> for H6 { type Output = (B1, H6); }
+impl HexSub<H0,B0> for H7 { type Output = (B0, H7); }
+impl HexSub<H0,B1> for H7 { type Output = (B0, H6); }
+impl HexSub<H1,B0> for H7 { type Output = (B0, H6); }
+impl HexSub<H1,B1> for H7 { type Output = (B0, H5); }
+impl HexSub<H2,B0> for H7 { type Output = (B0, H5); }
+impl HexSub<H2,B1> for H7 { type Output = (B0, H4); }
+impl HexSub<H3,B0> for H7 { type Output = (B0, H4); }
+impl HexSub<H3,B1> for H7 { type Output = (B0, H3); }
+impl HexSub<H4,B0> for H7 { type Output = (B0, H3); }
+impl HexSub<H4,B1> for H7 { type Output = (B0, H2); }
+impl HexSub<H5,B0> for H7 { type Output = (B0, H2); }
+impl HexSub<H5,B1> for H7 { type Output = (B0, H1); }
+impl HexSub<H6,B0> for H7 { type Output = (B0, H1); }
+impl HexSub<H6,B1> for H7 { type Output = (B0, H0); }
+impl HexSub<H7,B0> for H7 { type Output = (B0, H0); }
+impl HexSub<H7,B1> for H7 { type Output = (B1, H15); }
+impl HexSub<H8,B0> for H7 { type Output = (B1, H15); }
+impl HexSub<H8,B1> for H7 { type Output = (B1, H14); }
+impl HexSub<H9,B0> for H7 { type Output = (B1, H14); }
+impl HexSub<H9,B1> for H7 { type Output = (B1, H13); }
+impl HexSub<H10,B0> for H7 { type Output = (B1, H13); }
+impl HexSub<H10,B1> for H7 { type Output = (B1, H12); }
+impl HexSub<H11,B0> for H7 { type Output = (B1, H12); }
+impl HexSub<H11,B1> for H7 { type Output = (B1, H11); }
+impl HexSub<H12,B0> for H7 { type Output = (B1, H11); }
+impl HexSub<H12,B1> for H7 { type Output = (B1, H10); }
+impl HexSub<H13,B0> for H7 { type Output = (B1, H10); }
+impl HexSub<H13,B1> for H7 { type Output = (B1, H9); }
+impl HexSub<H14,B0> for H7 { type Output = (B1, H9); }
+impl HexSub<H14,B1> for H7 { type Output = (B1, H8); }
+impl HexSub<H15,B0> for H7 { type Output = (B1, H8); }
+impl HexSub<H15,B1> for H7 { type Output = (B1, H7); }
+impl HexSub<H0,B0> for H8 { type Output = (B0, H8); }
+impl HexSub<H0,B1> for H8 { type Output = (B0, H7); }
+impl HexSub<H1,B0> for H8 { type Output = (B0, H7); }
+impl HexSub<H1,B1> for H8 { type Output = (B0, H6); }
+impl HexSub<H2,B0> for H8 { type Output = (B0, H6); }
+impl HexSub<H2,B1> for H8 { type Output = (B0, H5); }
+impl HexSub<H3,B0> for H8 { type Output = (B0, H5); }
+impl HexSub<H3,B1> for H8 { type Output = (B0, H4); }
+impl HexSub<H4,B0> for H8 { type Output = (B0, H4); }
+impl HexSub<H4,B1> for H8 { type Output = (B0, H3); }
+impl HexSub<H5,B0> for H8 { type Output = (B0, H3); }
+impl HexSub<H5,B1> for H8 { type Output = (B0, H2); }
+impl HexSub<H6,B0> for H8 { type Output = (B0, H2); }
+impl HexSub<H6,B1> for H8 { type Output = (B0, H1); }
+impl HexSub<H7,B0> for H8 { type Output = (B0, H1); }
+impl HexSub<H7,B1> for H8 { type Output = (B0, H0); }
+impl HexSub<H8,B0> for H8 { type Output = (B0, H0); }
+impl HexSub<H8,B1> for H8 { type Output = (B1, H15); }
+impl HexSub<H9,B0> for H8 { type Output = (B1, H15); }
+impl HexSub<H9,B1> for H8 { type Output = (B1, H14); }
+impl HexSub<H10,B0> for H8 { type Output = (B1, H14); }
+impl HexSub<H10,B1> for H8 { type Output = (B1, H13); }
+impl HexSub<H11,B0> for H8 { type Output = (B1, H13); }
+impl HexSub<H11,B1> for H8 { type Output = (B1, H12); }
+impl HexSub<H12,B0> for H8 { type Output = (B1, H12); }
+impl HexSub<H12,B1> for H8 { type Output = (B1, H11); }
+impl HexSub<H13,B0> for H8 { type Output = (B1, H11); }
+impl HexSub<H13,B1> for H8 { type Output = (B1, H10); }
+impl HexSub<H14,B0> for H8 { type Output = (B1, H10); }
+impl HexSub<H14,B1> for H8 { type Output = (B1, H9); }
+impl HexSub<H15,B0> for H8 { type Output = (B1, H9); }
+impl HexSub<H15,B1> for H8 { type Output = (B1, H8); }
+impl HexSub<H0,B0> for H9 { type Output = (B0, H9); }
+impl HexSub<H0,B1> for H9 { type Output = (B0, H8); }
+impl HexSub<H1,B0> for H9 { type Output = (B0, H8); }
+impl HexSub<H1,B1> for H9 { type Output = (B0, H7); }
+impl HexSub<H2,B0> for H9 { type Output = (B0, H7); }
+impl HexSub<H2,B1> for H9 { type Output = (B0, H6); }
+impl HexSub<H3,B0> for H9 { type Output = (B0, H6); }
+impl HexSub<H3,B1> for H9 { type Output = (B0, H5); }
+impl HexSub<H4,B0> for H9 { type Output = (B0, H5); }
+impl HexSub<H4,B1> for H9 { type Output = (B0, H4); }
+impl HexSub<H5,B0> for H9 { type Output = (B0, H4); }
+impl HexSub<H5,B1> for H9 { type Output = (B0, H3); }
+impl HexSub<H6,B0> for H9 { type Output = (B0, H3); }
+impl HexSub<H6,B1> for H9 { type Output = (B0, H2); }
+impl HexSub<H7,B0> for H9 { type Output = (B0, H2); }
+impl HexSub<H7,B1> for H9 { type Output = (B0, H1); }
+impl HexSub<H8,B0> for H9 { type Output = (B0, H1); }
+impl HexSub<H8,B1> for H9 { type Output = (B0, H0); }
+impl HexSub<H9,B0> for H9 { type Output = (B0, H0); }
+impl HexSub<H9,B1> for H9 { type Output = (B1, H15); }
+impl HexSub<H10,B0> for H9 { type Output = (B1, H15); }
+impl HexSub<H10,B1> for H9 { type Output = (B1, H14); }
+impl HexSub<H11,B0> for H9 { type Output = (B1, H14); }
+impl HexSub<H11,B1> for H9 { type Output = (B1, H13); }
+impl HexSub<H12,B0> for H9 { type Output = (B1, H13); }
+impl HexSub<H12,B1> for H9 { type Output = (B1, H12); }
+impl HexSub<H13,B0> for H9 { type Output = (B1, H12); }
+impl HexSub<H13,B1> for H9 { type Output = (B1, H11); }
+impl HexSub<H14,B0> for H9 { type Output = (B1, H11); }
+impl HexSub<H14,B1> for H9 { type Output = (B1, H10); }
+impl HexSub<H15,B0> for H9 { type Output = (B1, H10); }
+impl HexSub<H15,B1> for H9 { type Output = (B1, H9); }
+impl HexSub<H0,B0> for H10 { type Output = (B0, H10); }
+impl HexSub<H0,B1> for H10 { type Output = (B0, H9); }
+impl HexSub<H1,B0> for H10 { type Output = (B0, H9); }
+impl HexSub<H1,B1> for H10 { type Output = (B0, H8); }
+impl HexSub<H2,B0> for H10 { type Output = (B0, H8); }
+impl HexSub<H2,B1> for H10 { type Output = (B0, H7); }
+impl HexSub<H3,B0> for H10 { type Output = (B0, H7); }
+impl HexSub<H3,B1> for H10 { type Output = (B0, H6); }
+impl HexSub<H4,B0> for H10 { type Output = (B0, H6); }
+impl HexSub<H4,B1> for H10 { type Output = (B0, H5); }
+impl HexSub<H5,B0> for H10 { type Output = (B0, H5); }
+impl HexSub<H5,B1> for H10 { type Output = (B0, H4); }
+impl HexSub<H6,B0> for H10 { type Output = (B0, H4); }
+impl HexSub<H6,B1> for H10 { type Output = (B0, H3); }
+impl HexSub<H7,B0> for H10 { type Output = (B0, H3); }
+impl HexSub<H7,B1> for H10 { type Output = (B0, H2); }
+impl HexSub<H8,B0> for H10 { type Output = (B0, H2); }
+impl HexSub<H8,B1> for H10 { type Output = (B0, H1); }
+impl HexSub<H9,B0> for H10 { type Output = (B0, H1); }
+impl HexSub<H9,B1> for H10 { type Output = (B0, H0); }
+impl HexSub<H10,B0> for H10 { type Output = (B0, H0); }
+impl HexSub<H10,B1> for H10 { type Output = (B1, H15); }
+impl HexSub<H11,B0> for H10 { type Output = (B1, H15); }
+impl HexSub<H11,B1> for H10 { type Output = (B1, H14); }
+impl HexSub<H12,B0> for H10 { type Output = (B1, H14); }
+impl HexSub<H12,B1> for H10 { type Output = (B1, H13); }
+impl HexSub<H13,B0> for H10 { type Output = (B1, H13); }
+impl HexSub<H13,B1> for H10 { type Output = (B1, H12); }
+impl HexSub<H14,B0> for H10 { type Output = (B1, H12); }
+impl HexSub<H14,B1> for H10 { type Output = (B1, H11); }
+impl HexSub<H15,B0> for H10 { type Output = (B1, H11); }
+impl HexSub<H15,B1> for H10 { type Output = (B1, H10); }
+impl HexSub<H0,B0> for H11 { type Output = (B0, H11); }
+impl HexSub<H0,B1> for H11 { type Output = (B0, H10); }
+impl HexSub<H1,B0> for H11 { type Output = (B0, H10); }
+impl HexSub<H1,B1> for H11 { type Output = (B0, H9); }
+impl HexSub<H2,B0> for H11 { type Output = (B0, H9); }
+impl HexSub<H2,B1> for H11 { type Output = (B0, H8); }
+impl HexSub<H3,B0> for H11 { type Output = (B0, H8); }
+impl HexSub<H3,B1> for H11 { type Output = (B0, H7); }
+impl HexSub<H4,B0> for H11 { type Output = (B0, H7); }
+impl HexSub<H4,B1> for H11 { type Output = (B0, H6); }
+impl HexSub<H5,B0> for H11 { type Output = (B0, H6); }
+impl HexSub<H5,B1> for H11 { type Output = (B0, H5); }
+impl HexSub<H6,B0> for H11 { type Output = (B0, H5); }
+impl HexSub<H6,B1> for H11 { type Output = (B0, H4); }
+impl HexSub<H7,B0> for H11 { type Output = (B0, H4); }
+impl HexSub<H7,B1> for H11 { type Output = (B0, H3); }
+impl HexSub<H8,B0> for H11 { type Output = (B0, H3); }
+impl HexSub<H8,B1> for H11 { type Output = (B0, H2); }
+impl HexSub<H9,B0> for H11 { type Output = (B0, H2); }
+impl HexSub<H9,B1> for H11 { type Output = (B0, H1); }
+impl HexSub<H10,B0> for H11 { type Output = (B0, H1); }
+impl HexSub<H10,B1> for H11 { type Output = (B0, H0); }
+impl HexSub<H11,B0> for H11 { type Output = (B0, H0); }
+impl HexSub<H11,B1> for H11 { type Output = (B1, H15); }
+impl HexSub<H12,B0> for H11 { type Output = (B1, H15); }
+impl HexSub<H12,B1> for H11 { type Output = (B1, H14); }
+impl HexSub<H13,B0> for H11 { type Output = (B1, H14); }
+impl HexSub<H13,B1> for H11 { type Output = (B1, H13); }
+impl HexSub<H14,B0> for H11 { type Output = (B1, H13); }
+impl HexSub<H14,B1> for H11 { type Output = (B1, H12); }
+impl HexSub<H15,B0> for H11 { type Output = (B1, H12); }
+impl HexSub<H15,B1> for H11 { type Output = (B1, H11); }
+impl HexSub<H0,B0> for H12 { type Output = (B0, H12); }
+impl HexSub<H0,B1> for H12 { type Output = (B0, H11); }
+impl HexSub<H1,B0> for H12 { type Output = (B0, H11); }
+impl HexSub<H1,B1> for H12 { type Output = (B0, H10); }
+impl HexSub<H2,B0> for H12 { type Output = (B0, H10); }
+impl HexSub<H2,B1> for H12 { type Output = (B0, H9); }
+impl HexSub<H3,B0> for H12 { type Output = (B0, H9); }
+impl HexSub<H3,B1> for H12 { type Output = (B0, H8); }
+impl HexSub<H4,B0> for H12 { type Output = (B0, H8); }
+impl HexSub<H4,B1> for H12 { type Output = (B0, H7); }
+impl HexSub<H5,B0> for H12 { type Output = (B0, H7); }
+impl HexSub<H5,B1> for H12 { type Output = (B0, H6); }
+impl HexSub<H6,B0> for H12 { type Output = (B0, H6); }
+impl HexSub<H6,B1> for H12 { type Output = (B0, H5); }
+impl HexSub<H7,B0> for H12 { type Output = (B0, H5); }
+impl HexSub<H7,B1> for H12 { type Output = (B0, H4); }
+impl HexSub<H8,B0> for H12 { type Output = (B0, H4); }
+impl HexSub<H8,B1> for H12 { type Output = (B0, H3); }
+impl HexSub<H9,B0> for H12 { type Output = (B0, H3); }
+impl HexSub<H9,B1> for H12 { type Output = (B0, H2); }
+impl HexSub<H10,B0> for H12 { type Output = (B0, H2); }
+impl HexSub<H10,B1> for H12 { type Output = (B0, H1); }
+impl HexSub<H11,B0> for H12 { type Output = (B0, H1); }
+impl HexSub<H11,B1> for H12 { type Output = (B0, H0); }
+impl HexSub<H12,B0> for H12 { type Output = (B0, H0); }
+impl HexSub<H12,B1> for H12 { type Output = (B1, H15); }
+impl HexSub<H13,B0> for H12 { type Output = (B1, H15); }
+impl HexSub<H13,B1> for H12 { type Output = (B1, H14); }
+impl HexSub<H14,B0> for H12 { type Output = (B1, H14); }
+impl HexSub<H14,B1> for H12 { type Output = (B1, H13); }
+impl HexSub<H15,B0> for H12 { type Output = (B1, H13); }
+impl HexSub<H15,B1> for H12 { type Output = (B1, H12); }
+impl HexSub<H0,B0> for H13 { type Output = (B0, H13); }
+impl HexSub<H0,B1> for H13 { type Output = (B0, H12); }
+impl HexSub<H1,B0> for H13 { type Output = (B0, H12); }
+impl HexSub<H1,B1> for H13 { type Output = (B0, H11); }
+impl HexSub<H2,B0> for H13 { type Output = (B0, H11); }
+impl HexSub<H2,B1> for H13 { type Output = (B0, H10); }
+impl HexSub<H3,B0> for H13 { type Output = (B0, H10); }
+impl HexSub<H3,B1> for H13 { type Output = (B0, H9); }
+impl HexSub<H4,B0> for H13 { type Output = (B0, H9); }
+impl HexSub<H4,B1> for H13 { type Output = (B0, H8); }
+impl HexSub<H5,B0> for H13 { type Output = (B0, H8); }
+impl HexSub<H5,B1> for H13 { type Output = (B0, H7); }
+impl HexSub<H6,B0> for H13 { type Output = (B0, H7); }
+impl HexSub<H6,B1> for H13 { type Output = (B0, H6); }
+impl HexSub<H7,B0> for H13 { type Output = (B0, H6); }
+impl HexSub<H7,B1> for H13 { type Output = (B0, H5); }
+impl HexSub<H8,B0> for H13 { type Output = (B0, H5); }
+impl HexSub<H8,B1> for H13 { type Output = (B0, H4); }
+impl HexSub<H9,B0> for H13 { type Output = (B0, H4); }
+impl HexSub<H9,B1> for H13 { type Output = (B0, H3); }
+impl HexSub<H10,B0> for H13 { type Output = (B0, H3); }
+impl HexSub<H10,B1> for H13 { type Output = (B0, H2); }
+impl HexSub<H11,B0> for H13 { type Output = (B0, H2); }
+impl HexSub<H11,B1> for H13 { type Output = (B0, H1); }
+impl HexSub<H12,B0> for H13 { type Output = (B0, H1); }
+impl HexSub<H12,B1> for H13 { type Output = (B0, H0); }
+impl HexSub<H13,B0> for H13 { type Output = (B0, H0); }
+impl HexSub<H13,B1> for H13 { type Output = (B1, H15); }
+impl HexSub<H14,B0> for H13 { type Output = (B1, H15); }
+impl HexSub<H14,B1> for H13 { type Output = (B1, H14); }
+impl HexSub<H15,B0> for H13 { type Output = (B1, H14); }
+impl HexSub<H15,B1> for H13 { type Output = (B1, H13); }
+impl HexSub<H0,B0> for H14 { type Output = (B0, H14); }
+impl HexSub<H0,B1> for H14 { type Output = (B0, H13); }
+impl HexSub<H1,B0> for H14 { type Output = (B0, H13); }
+impl HexSub<H1,B1> for H14 { type Output = (B0, H12); }
+impl HexSub<H2,B0> for H14 { type Output = (B0, H12); }
+impl HexSub<H2,B1> for H14 { type Output = (B0, H11); }
+impl HexSub<H3,B0> for H14 { type Output = (B0, H11); }
+impl HexSub<H3,B1> for H14 { type Output = (B0, H10); }
+impl HexSub<H4,B0> for H14 { type Output = (B0, H10); }
+impl HexSub<H4,B1> for H14 { type Output = (B0, H9); }
+impl HexSub<H5,B0> for H14 { type Output = (B0, H9); }
+impl HexSub<H5,B1> for H14 { type Output = (B0, H8); }
+impl HexSub<H6,B0> for H14 { type Output = (B0, H8); }
+impl HexSub<H6,B1> for H14 { type Output = (B0, H7); }
+impl HexSub<H7,B0> for H14 { type Output = (B0, H7); }
+impl HexSub<H7,B1> for H14 { type Output = (B0, H6); }
+impl HexSub<H8,B0> for H14 { type Output = (B0, H6); }
+impl HexSub<H8,B1> for H14 { type Output = (B0, H5); }
+impl HexSub<H9,B0> for H14 { type Output = (B0, H5); }
+impl HexSub<H9,B1> for H14 { type Output = (B0, H4); }
+impl HexSub<H10,B0> for H14 { type Output = (B0, H4); }
+impl HexSub<H10,B1> for H14 { type Output = (B0, H3); }
+impl HexSub<H11,B0> for H14 { type Output = (B0, H3); }
+impl HexSub<H11,B1> for H14 { type Output = (B0, H2); }
+impl HexSub<H12,B0> for H14 { type Output = (B0, H2); }
+impl HexSub<H12,B1> for H14 { type Output = (B0, H1); }
+impl HexSub<H13,B0> for H14 { type Output = (B0, H1); }
+impl HexSub<H13,B1> for H14 { type Output = (B0, H0); }
+impl HexSub<H14,B0> for H14 { type Output = (B0, H0); }
+impl HexSub<H14,B1> for H14 { type Output = (B1, H15); }
+impl HexSub<H15,B0> for H14 { type Output = (B1, H15); }
+impl HexSub<H15,B1> for H14 { type Output = (B1, H14); }
+impl HexSub<H0,B0> for H15 { type Output = (B0, H15); }
+impl HexSub<H0,B1> for H15 { type Output = (B0, H14); }
+impl HexSub<H1,B0> for H15 { type Output = (B0, H14); }
+impl HexSub<H1,B1> for H15 { type Output = (B0, H13); }
+impl HexSub<H2,B0> for H15 { type Output = (B0, H13); }
+impl HexSub<H2,B1> for H15 { type Output = (B0, H12); }
+impl HexSub<H3,B0> for H15 { type Output = (B0, H12); }
+impl HexSub<H3,B1> for H15 { type Output = (B0, H11); }
+impl HexSub<H4,B0> for H15 { type Output = (B0, H11); }
+impl HexSub<H4,B1> for H15 { type Output = (B0, H10); }
+impl HexSub<H5,B0> for H15 { type Output = (B0, H10); }
+impl HexSub<H5,B1> for H15 { type Output = (B0, H9); }
+impl HexSub<H6,B0> for H15 { type Output = (B0, H9); }
+impl HexSub<H6,B1> for H15 { type Output = (B0, H8); }
+impl HexSub<H7,B0> for H15 { type Output = (B0, H8); }
+impl HexSub<H7,B1> for H15 { type Output = (B0, H7); }
+impl HexSub<H8,B0> for H15 { type Output = (B0, H7); }
+impl HexSub<H8,B1> for H15 { type Output = (B0, H6); }
+impl HexSub<H9,B0> for H15 { type Output = (B0, H6); }
+impl HexSub<H9,B1> for H15 { type Output = (B0, H5); }
+impl HexSub<H10,B0> for H15 { type Output = (B0, H5); }
+impl HexSub<H10,B1> for H15 { type Output = (B0, H4); }
+impl HexSub<H11,B0> for H15 { type Output = (B0, H4); }
+impl HexSub<H11,B1> for H15 { type Output = (B0, H3); }
+impl HexSub<H12,B0> for H15 { type Output = (B0, H3); }
+impl HexSub<H12,B1> for H15 { type Output = (B0, H2); }
+impl HexSub<H13,B0> for H15 { type Output = (B0, H2); }
+impl HexSub<H13,B1> for H15 { type Output = (B0, H1); }
+impl HexSub<H14,B0> for H15 { type Output = (B0, H1); }
+impl HexSub<H14,B1> for H15 { type Output = (B0, H0); }
+impl HexSub<H15,B0> for H15 { type Output = (B0, H0); }
+impl HexSub<H15,B1> for H15 { type Output = (B1, H15); }
+
+// Self*Rhs modulo 16.
+pub trait HexMulLow<Rhs> {
+    type Output;
+}
+impl HexMulLow<H0> for H0 { type Output = H0; }
+impl HexMulLow<H1> for H0 { type Output = H0; }
+impl HexMulLow<H2> for H0 { type Output = H0; }
+impl HexMulLow<H3> for H0 { type Output = H0; }
+impl HexMulLow<H4> for H0 { type Output = H0; }
+impl HexMulLow<H5> for H0 { type Output = H0; }
+impl HexMulLow<H6> for H0 { type Output = H0; }
+impl HexMulLow<H7> for H0 { type Output = H0; }
+impl HexMulLow<H8> for H0 { type Output = H0; }
+impl HexMulLow<H9> for H0 { type Output = H0; }
+impl HexMulLow<H10> for H0 { type Output = H0; }
+impl HexMulLow<H11> for H0 { type Output = H0; }
+impl HexMulLow<H12> for H0 { type Output = H0; }
+impl HexMulLow<H13> for H0 { type Output = H0; }
+impl HexMulLow<H14> for H0 { type Output = H0; }
+impl HexMulLow<H15> for H0 { type Output = H0; }
+impl HexMulLow<H0> for H1 { type Output = H0; }
+impl HexMulLow<H1> for H1 { type Output = H1; }
+impl HexMulLow<H2> for H1 { type Output = H2; }
+impl HexMulLow<H3> for H1 { type Output = H3; }
+impl HexMulLow<H4> for H1 { type Output = H4; }
+impl HexMulLow<H5> for H1 { type Output = H5; }
+impl HexMulLow<H6> for H1 { type Output = H6; }
+impl HexMulLow<H7> for H1 { type Output = H7; }
+impl HexMulLow<H8> for H1 { type Output = H8; }
+impl HexMulLow<H9> for H1 { type Output = H9; }
+impl HexMulLow<H10> for H1 { type Output = H10; }
+impl HexMulLow<H11> for H1 { type Output = H11; }
+impl HexMulLow<H12> for H1 { type Output = H12; }
+impl HexMulLow<H13> for H1 { type Output = H13; }
+impl HexMulLow<H14> for H1 { type Output = H14; }
+impl HexMulLow<H15> for H1 { type Output = H15; }
+impl HexMulLow<H0> for H2 { type Output = H0; }
+impl HexMulLow<H1> for H2 { type Output = H2; }
+impl HexMulLow<H2> for H2 { type Output = H4; }
+impl HexMulLow<H3> for H2 { type Output = H6; }
+impl HexMulLow<H4> for H2 { type Output = H8; }
+impl HexMulLow<H5> for H2 { type Output = H10; }
+impl HexMulLow<H6> for H2 { type Output = H12; }
+impl HexMulLow<H7> for H2 { type Output = H14; }
+impl HexMulLow<H8> for H2 { type Output = H0; }
+impl HexMulLow<H9> for H2 { type Output = H2; }
+impl HexMulLow<H10> for H2 { type Output = H4; }
+impl HexMulLow<H11> for H2 { type Output = H6; }
+impl HexMulLow<H12> for H2 { type Output = H8; }
+impl HexMulLow<H13> for H2 { type Output = H10; }
+impl HexMulLow<H14> for H2 { type Output = H12; }
+impl HexMulLow<H15> for H2 { type Output = H14; }
+impl HexMulLow<H0> for H3 { type Output = H0; }
+impl HexMulLow<H1> for H3 { type Output = H3; }
+impl HexMulLow<H2> for H3 { type Output = H6; }
+impl HexMulLow<H3> for H3 { type Output = H9; }
+impl HexMulLow<H4> for H3 { type Output = H12; }
+impl HexMulLow<H5> for H3 { type Output = H15; }
+impl HexMulLow<H6> for H3 { type Output = H2; }
+impl HexMulLow<H7> for H3 { type Output = H5; }
+impl HexMulLow<H8> for H3 { type Output = H8; }
+impl HexMulLow<H9> for H3 { type Output = H11; }
+impl HexMulLow<H10> for H3 { type Output = H14; }
+impl HexMulLow<H11> for H3 { type Output = H1; }
+impl HexMulLow<H12> for H3 { type Output = H4; }
+impl HexMulLow<H13> for H3 { type Output = H7; }
+impl HexMulLow<H14> for H3 { type Output = H10; }
+impl HexMulLow<H15> for H3 { type Output = H13; }
+impl HexMulLow<H0> for H4 { type Output = H0; }
+impl HexMulLow<H1> for H4 { type Output = H4; }
+impl HexMulLow<H2> for H4 { type Output = H8; }
+impl HexMulLow<H3> for H4 { type Output = H12; }
+impl HexMulLow<H4> for H4 { type Output = H0; }
+impl HexMulLow<H5> for H4 { type Output = H4; }
+impl HexMulLow<H6> for H4 { type Output = H8; }
+impl HexMulLow<H7> for H4 { type Output = H12; }
+impl HexMulLow<H8> for H4 { type Output = H0; }
+impl HexMulLow<H9> for H4 { type Output = H4; }
+impl HexMulLow<H10> for H4 { type Output = H8; }
+impl HexMulLow<H11> for H4 { type Output = H12; }
+impl HexMulLow<H12> for H4 { type Output = H0; }
+impl HexMulLow<H13> for H4 { type Output = H4; }
+impl HexMulLow<H14> for H4 { type Output = H8; }
+impl HexMulLow<H15> for H4 { type Output = H12; }
+impl HexMulLow<H0> for H5 { type Output = H0; }
+impl HexMulLow<H1> for H5 { type Output = H5; }
+impl HexMulLow<H2> for H5 { type Output = H10; }
+impl HexMulLow<H3> for H5 { type Output = H15; }
+impl HexMulLow<H4> for H5 { type Output = H4; }
+impl HexMulLow<H5> for H5 { type Output = H9; }
+impl HexMulLow<H6> for H5 { type Output = H14; }
+impl HexMulLow<H7> for H5 { type Output = H3; }
+impl HexMulLow<H8> for H5 { type Output = H8; }
+impl HexMulLow<H9> for H5 { type Output = H13; }
+impl HexMulLow<H10> for H5 { type Output = H2; }
+impl HexMulLow<H11> for H5 { type Output = H7; }
+impl HexMulLow<H12> for H5 { type Output = H12; }
+impl HexMulLow<H13> for H5 { type Output = H1; }
+impl HexMulLow<H14> for H5 { type Output = H6; }
+impl HexMulLow<H15> for H5 { type Output = H11; }
+impl HexMulLow<H0> for H6 { type Output = H0; }
+impl HexMulLow<H1> for H6 { type Output = H6; }
+impl HexMulLow<H2> for H6 { type Output = H12; }
+impl HexMulLow<H3> for H6 { type Output = H2; }
+impl HexMulLow<H4> for H6 { type Output = H8; }
+impl HexMulLow<H5> for H6 { type Output = H14; }
+impl HexMulLow<H6> for H6 { type Output = H4; }
+impl HexMulLow<H7> for H6 { type Output = H10; }
+impl HexMulLow<H8> for H6 { type Output = H0; }
+impl HexMulLow<H9> for H6 { type Output = H6; }
+impl HexMulLow<H10> for H6 { type Output = H12; }
+impl HexMulLow<H11> for H6 { type Output = H2; }
+impl HexMulLow<H12> for H6 { type Output = H8; }
+impl HexMulLow<H13> for H6 { type Output = H14; }
+impl HexMulLow<H14> for H6 { type Output = H4; }
+impl HexMulLow<H15> for H6 { type Output = H10; }
+impl HexMulLow<H0> for H7 { type Output = H0; }
+impl HexMulLow<H1> for H7 { type Output = H7; }
+impl HexMulLow<H2> for H7 { type Output = H14; }
+impl HexMulLow<H3> for H7 { type Output = H5; }
+impl HexMulLow<H4> for H7 { type Output = H12; }
+impl HexMulLow<H5> for H7 { type Output = H3; }
+impl HexMulLow<H6> for H7 { type Output = H10; }
+impl HexMulLow<H7> for H7 { type Output = H1; }
+impl HexMulLow<H8> for H7 { type Output = H8; }
+impl HexMulLow<H9> for H7 { type Output = H15; }
+impl HexMulLow<H10> for H7 { type Output = H6; }
+impl HexMulLow<H11> for H7 { type Output = H13; }
+impl HexMulLow<H12> for H7 { type Output = H4; }
+impl HexMulLow<H13> for H7 { type Output = H11; }
+impl HexMulLow<H14> for H7 { type Output = H2; }
+impl HexMulLow<H15> for H7 { type Output = H9; }
+impl HexMulLow<H0> for H8 { type Output = H0; }
+impl HexMulLow<H1> for H8 { type Output = H8; }
+impl HexMulLow<H2> for H8 { type Output = H0; }
+impl HexMulLow<H3> for H8 { type Output = H8; }
+impl HexMulLow<H4> for H8 { type Output = H0; }
+impl HexMulLow<H5> for H8 { type Output = H8; }
+impl HexMulLow<H6> for H8 { type Output = H0; }
+impl HexMulLow<H7> for H8 { type Output = H8; }
+impl HexMulLow<H8> for H8 { type Output = H0; }
+impl HexMulLow<H9> for H8 { type Output = H8; }
+impl HexMulLow<H10> for H8 { type Output = H0; }
+impl HexMulLow<H11> for H8 { type Output = H8; }
+impl HexMulLow<H12> for H8 { type Output = H0; }
+impl HexMulLow<H13> for H8 { type Output = H8; }
+impl HexMulLow<H14> for H8 { type Output = H0; }
+impl HexMulLow<H15> for H8 { type Output = H8; }
+impl HexMulLow<H0> for H9 { type Output = H0; }
+impl HexMulLow<H1> for H9 { type Output = H9; }
+impl HexMulLow<H2> for H9 { type Output = H2; }
+impl HexMulLow<H3> for H9 { type Output = H11; }
+impl HexMulLow<H4> for H9 { type Output = H4; }
+impl HexMulLow<H5> for H9 { type Output = H13; }
+impl HexMulLow<H6> for H9 { type Output = H6; }
+impl HexMulLow<H7> for H9 { type Output = H15; }
+impl HexMulLow<H8> for H9 { type Output = H8; }
+impl HexMulLow<H9> for H9 { type Output = H1; }
+impl HexMulLow<H10> for H9 { type Output = H10; }
+impl HexMulLow<H11> for H9 { type Output = H3; }
+impl HexMulLow<H12> for H9 { type Output = H12; }
+impl HexMulLow<H13> for H9 { type Output = H5; }
+impl HexMulLow<H14> for H9 { type Output = H14; }
+impl HexMulLow<H15> for H9 { type Output = H7; }
+impl HexMulLow<H0> for H10 { type Output = H0; }
+impl HexMulLow<H1> for H10 { type Output = H10; }
+impl HexMulLow<H2> for H10 { type Output = H4; }
+impl HexMulLow<H3> for H10 { type Output = H14; }
+impl HexMulLow<H4> for H10 { type Output = H8; }
+impl HexMulLow<H5> for H10 { type Output = H2; }
+impl HexMulLow<H6> for H10 { type Output = H12; }
+impl HexMulLow<H7> for H10 { type Output = H6; }
+impl HexMulLow<H8> for H10 { type Output = H0; }
+impl HexMulLow<H9> for H10 { type Output = H10; }
+impl HexMulLow<H10> for H10 { type Output = H4; }
+impl HexMulLow<H11> for H10 { type Output = H14; }
+impl HexMulLow<H12> for H10 { type Output = H8; }
+impl HexMulLow<H13> for H10 { type Output = H2; }
+impl HexMulLow<H14> for H10 { type Output = H12; }
+impl HexMulLow<H15> for H10 { type Output = H6; }
+impl HexMulLow<H0> for H11 { type Output = H0; }
+impl HexMulLow<H1> for H11 { type Output = H11; }
+impl HexMulLow<H2> for H11 { type Output = H6; }
+impl HexMulLow<H3> for H11 { type Output = H1; }
+impl HexMulLow<H4> for H11 { type Output = H12; }
+impl HexMulLow<H5> for H11 { type Output = H7; }
+impl HexMulLow<H6> for H11 { type Output = H2; }
+impl HexMulLow<H7> for H11 { type Output = H13; }
+impl HexMulLow<H8> for H11 { type Output = H8; }
+impl HexMulLow<H9> for H11 { type Output = H3; }
+impl HexMulLow<H10> for H11 { type Output = H14; }
+impl HexMulLow<H11> for H11 { type Output = H9; }
+impl HexMulLow<H12> for H11 { type Output = H4; }
+impl HexMulLow<H13> for H11 { type Output = H15; }
+impl HexMulLow<H14> for H11 { type Output = H10; }
+impl HexMulLow<H15> for H11 { type Output = H5; }
+impl HexMulLow<H0> for H12 { type Output = H0; }
+impl HexMulLow<H1> for H12 { type Output = H12; }
+impl HexMulLow<H2> for H12 { type Output = H8; }
+impl HexMulLow<H3> for H12 { type Output = H4; }
+impl HexMulLow<H4> for H12 { type Output = H0; }
+impl HexMulLow<H5> for H12 { type Output = H12; }
+impl HexMulLow<H6> for H12 { type Output = H8; }
+impl HexMulLow<H7> for H12 { type Output = H4; }
+impl HexMulLow<H8> for H12 { type Output = H0; }
+impl HexMulLow<H9> for H12 { type Output = H12; }
+impl HexMulLow<H10> for H12 { type Output = H8; }
+impl HexMulLow<H11> for H12 { type Output = H4; }
+impl HexMulLow<H12> for H12 { type Output = H0; }
+impl HexMulLow<H13> for H12 { type Output = H12; }
+impl HexMulLow<H14> for H12 { type Output = H8; }
+impl HexMulLow<H15> for H12 { type Output = H4; }
+impl HexMulLow<H0> for H13 { type Output = H0; }
+impl HexMulLow<H1> for H13 { type Output = H13; }
+impl HexMulLow<H2> for H13 { type Output = H10; }
+impl HexMulLow<H3> for H13 { type Output = H7; }
+impl HexMulLow<H4> for H13 { type Output = H4; }
+impl HexMulLow<H5> for H13 { type Output = H1; }
+impl HexMulLow<H6> for H13 { type Output = H14; }
+impl HexMulLow<H7> for H13 { type Output = H11; }
+impl HexMulLow<H8> for H13 { type Output = H8; }
+impl HexMulLow<H9> for H13 { type Output = H5; }
+impl HexMulLow<H10> for H13 { type Output = H2; }
+impl HexMulLow<H11> for H13 { type Output = H15; }
+impl HexMulLow<H12> for H13 { type Output = H12; }
+impl HexMulLow<H13> for H13 { type Output = H9; }
+impl HexMulLow<H14> for H13 { type Output = H6; }
+impl HexMulLow<H15> for H13 { type Output = H3; }
+impl HexMulLow<H0> for H14 { type Output = H0; }
+impl HexMulLow<H1> for H14 { type Output = H14; }
+impl HexMulLow<H2> for H14 { type Output = H12; }
+impl HexMulLow<H3> for H14 { type Output = H10; }
+impl HexMulLow<H4> for H14 { type Output = H8; }
+impl HexMulLow<H5> for H14 { type Output = H6; }
+impl HexMulLow<H6> for H14 { type Output = H4; }
+impl HexMulLow<H7> for H14 { type Output = H2; }
+impl HexMulLow<H8> for H14 { type Output = H0; }
+impl HexMulLow<H9> for H14 { type Output = H14; }
+impl HexMulLow<H10> for H14 { type Output = H12; }
+impl HexMulLow<H11> for H14 { type Output = H10; }
+impl HexMulLow<H12> for H14 { type Output = H8; }
+impl HexMulLow<H13> for H14 { type Output = H6; }
+impl HexMulLow<H14> for H14 { type Output = H4; }
+impl HexMulLow<H15> for H14 { type Output = H2; }
+impl HexMulLow<H0> for H15 { type Output = H0; }
+impl HexMulLow<H1> for H15 { type Output = H15; }
+impl HexMulLow<H2> for H15 { type Output = H14; }
+impl HexMulLow<H3> for H15 { type Output = H13; }
+impl HexMulLow<H4> for H15 { type Output = H12; }
+impl HexMulLow<H5> for H15 { type Output = H11; }
+impl HexMulLow<H6> for H15 { type Output = H10; }
+impl HexMulLow<H7> for H15 { type Output = H9; }
+impl HexMulLow<H8> for H15 { type Output = H8; }
+impl HexMulLow<H9> for H15 { type Output = H7; }
+impl HexMulLow<H10> for H15 { type Output = H6; }
+impl HexMulLow<H11> for H15 { type Output = H5; }
+impl HexMulLow<H12> for H15 { type Output = H4; }
+impl HexMulLow<H13> for H15 { type Output = H3; }
+impl HexMulLow<H14> for H15 { type Output = H2; }
+impl HexMulLow<H15> for H15 { type Output = H1; }
+
+// -1/Self modulo 16, for odd digits.
+pub trait HexNegInv {
+    type Output;
+}
+impl HexNegInv for H1 { type Output = H15; }
+impl HexNegInv for H3 { type Output = H5; }
+impl HexNegInv for H5 { type Output = H3; }
+impl HexNegInv for H7 { type Output = H9; }
+impl HexNegInv for H9 { type Output = H7; }
+impl HexNegInv for H11 { type Output = H13; }
+impl HexNegInv for H13 { type Output = H11; }
+impl HexNegInv for H15 { type Output = H1; }
+
+// The digit with the given four bits, most significant first.
+pub trait HexOfBits {
+    type Output;
+}
+impl HexOfBits for (B0, B0, B0, B0) { type Output = H0; }
+impl HexOfBits for (B0, B0, B0, B1) { type Output = H1; }
+impl HexOfBits for (B0, B0, B1, B0) { type Output = H2; }
+impl HexOfBits for (B0, B0, B1, B1) { type Output = H3; }
+impl HexOfBits for (B0, B1, B0, B0) { type Output = H4; }
+impl HexOfBits for (B0, B1, B0, B1) { type Output = H5; }
+impl HexOfBits for (B0, B1, B1, B0) { type Output = H6; }
+impl HexOfBits for (B0, B1, B1, B1) { type Output = H7; }
+impl HexOfBits for (B1, B0, B0, B0) { type Output = H8; }
+impl HexOfBits for (B1, B0, B0, B1) { type Output = H9; }
+impl HexOfBits for (B1, B0, B1, B0) { type Output = H10; }
+impl HexOfBits for (B1, B0, B1, B1) { type Output = H11; }
+impl HexOfBits for (B1, B1, B0, B0) { type Output = H12; }
+impl HexOfBits for (B1, B1, B0, B1) { type Output = H13; }
+impl HexOfBits for (B1, B1, B1, B0) { type Output = H14; }
+impl HexOfBits for (B1, B1, B1, B1) { type Output = H15; }
@@ -0,0 +1,57 @@
+// src/private/list.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use typenum::array::{ATerm, TArr};
+use typenum::operator_aliases::Eq;
+use typenum::type_operators::IsEqual;
+
+use {IsCoprime, Prime};
+use super::{CoprimePair, DistinctPair};
+
+
+// A marker trait for an integer that is coprime to every element of
+// the list L.
+pub trait CoprimeToAll<L> {}
+
+impl<V> CoprimeToAll<ATerm> for V {}
+
+impl<V,W,L> CoprimeToAll<TArr<W,L>> for V
+    where V: IsCoprime<W> + CoprimeToAll<L>,
+          <V as IsCoprime<W>>::Output: CoprimePair<V,W> {}
+
+
+// A marker trait for an integer that differs from every element of
+// the list L.
+pub trait DistinctFromAll<L> {}
+
+impl<V> DistinctFromAll<ATerm> for V {}
+
+impl<V,W,L> DistinctFromAll<TArr<W,L>> for V
+    where V: IsEqual<W> + DistinctFromAll<L>,
+          Eq<V,W>: DistinctPair<V,W> {}
+
+
+// Writing the primes of a list to the start of a slice, which must
+// hold at least as many elements as the list, for
+// `PrimeArray::to_array`.
+pub trait FillPrimes {
+    fn fill(out: &mut [u64]);
+}
+
+impl FillPrimes for ATerm {
+    fn fill(_: &mut [u64]) {}
+}
+
+impl<V,A> FillPrimes for TArr<V,A> where V: Prime, A: FillPrimes {
+    fn fill(out: &mut [u64]) {
+        out[0] = V::U64;
+        A::fill(&mut out[1..]);
+    }
+}
@@ -10,11 +10,13 @@
 
 use core::ops::Add;
 
-use typenum::bit::{Bit, B0};
+use typenum::bit::{Bit, B0, B1};
 use typenum::consts::{False, True};
 use typenum::operator_aliases::Sum;
 use typenum::uint::{Unsigned, UInt, UTerm};
 
+use CertifiedPrime;
+
 
 // A type operator for dividing Self by 2, rounding up if Self is odd.
 pub trait CeilDivBy2 {
@@ -83,3 +85,85 @@ impl<U,B> IsNonZero for UInt<U,B> {
 
 // Alias for the result of IsNonZero
 pub type IsNonZeroOut<N> = <N as IsNonZero>::Output;
+
+
+// A type operator. The Output is True if Self is 1 and False for any
+// other unsigned integer.
+pub trait IsOne {
+    type Output: Bit;
+}
+
+impl IsOne for UTerm {
+    type Output = False;
+}
+
+impl IsOne for UInt<UTerm,B1> {
+    type Output = True;
+}
+
+impl<U,Ba,Bb> IsOne for UInt<UInt<U,Ba>,Bb> {
+    type Output = False;
+}
+
+// Alias for the result of IsOne
+pub type IsOneOut<N> = <N as IsOne>::Output;
+
+
+// A type operator. Self is the remainder of division by D. If Self is
+// zero, the Output is D. Otherwise, the Output is UTerm.
+pub trait DivisorIfZero<D> {
+    type Output;
+}
+
+impl<D> DivisorIfZero<D> for UTerm {
+    type Output = D;
+}
+
+impl<U,B,D> DivisorIfZero<D> for UInt<U,B> {
+    type Output = UTerm;
+}
+
+// Alias for the result of DivisorIfZero<D>.
+pub type DivisorIfZeroOut<N,D> = <N as DivisorIfZero<D>>::Output;
+
+
+// A type operator. If Self is zero, the Output is D. Otherwise, the
+// Output is Self unchanged.
+pub trait ReplaceZero<D> {
+    type Output;
+}
+
+impl<D> ReplaceZero<D> for UTerm {
+    type Output = D;
+}
+
+impl<U,B,D> ReplaceZero<D> for UInt<U,B> {
+    type Output = Self;
+}
+
+// Alias for the result of ReplaceZero<D>.
+pub type ReplaceZeroOut<N,D> = <N as ReplaceZero<D>>::Output;
+
+
+// The modulus of Fp, Montgomery, Barrett and Fpk, which keep residues
+// in a u64. It fails to evaluate for a prime that does not fit, which
+// Unsigned::U64 would silently truncate. Unsigned::U128 wraps around
+// too, but only beyond 2^128, where no integer can be shown to be
+// prime at compile time, with or without a certificate.
+pub trait ModulusU64 {
+    const MODULUS: u64;
+}
+
+impl<P> ModulusU64 for P where P: CertifiedPrime {
+    const MODULUS: u64 = {
+        assert!(<P::Value as Unsigned>::U128 <= u64::MAX as u128, "the modulus does not fit in a `u64`");
+        <P::Value as Unsigned>::U64
+    };
+}
+
+#[test]
+fn test_modulus_u64() {
+    use typenum::consts::*;
+    assert_eq!(<U2 as ModulusU64>::MODULUS, 2);
+    assert_eq!(<U97 as ModulusU64>::MODULUS, 97);
+}
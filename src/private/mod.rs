@@ -9,32 +9,68 @@
 // modified, or distributed except according to those terms.
 
 use typenum::bit::{Bit, B1};
-use typenum::consts::False;
+use typenum::consts::{False, True};
 use typenum::operator_aliases::Length;
 use typenum::type_operators::Len;
 use typenum::uint::{UInt, UTerm};
 
-use self::misc::{CeilDivBy2, CeilDivBy2Out};
+use {Composite, Prime};
+use self::misc::{CeilDivBy2, CeilDivBy2Out, ReplaceZero, ReplaceZeroOut};
 use self::trial_division::TrialDivisionTreeBranch0;
 
+pub use self::barrett::PrivateBarrett;
+pub use self::crt::{PrivateCrt, PrivateCrtBasis};
+pub use self::euclid::{Gcd, GcdOut, ModInverse, ModInverseOut};
+pub use self::gf2::{PrivateIsIrreducibleGf2, PrivateIsPrimitiveGf2};
+pub use self::gfp::{FillTail, PrivateFpModulus, PrivateIsIrreducibleFp};
+pub use self::gaussian::{Magnitude, MagnitudeOut, PrivateIsGaussianPrime};
+pub use self::list::{CoprimeToAll, DistinctFromAll, FillPrimes};
+pub use self::misc::{IsOne, IsOneOut, ModulusU64};
+pub use self::montgomery::PrivateMontgomery;
+pub use self::next_prime::{PrimeAtLeast, PrimeAtLeastOut, PrivateTwinOf};
+pub use self::pocklington::{Pocklington, PocklingtonFactor, PrivateCertificate};
+pub use self::power::{ModPowOut, PrivateModPow};
+pub use self::primitive_root::{PrimeFactors, PrimeFactorsOut, PrivatePrimitiveRoot};
+pub use self::reduction::{Reduce, ReduceOut};
+pub use self::sieve::PrivatePrimesUpTo;
+#[cfg(feature = "macros")] pub use typenum_prime_macros::prime;
+
+mod barrett;
+mod crt;
+mod euclid;
+mod gaussian;
+mod gf2;
+mod gfp;
+mod hex;
+mod list;
 mod misc;
+mod montgomery;
+mod next_prime;
+mod pocklington;
+mod power;
+mod primitive_root;
 mod reduction;
+mod sieve;
 mod trial_division;
 
 
-// A type operator for primality testing.
+// A type operator for primality testing. The Witness is the smallest
+// prime divisor of Self when Self is composite, and Self otherwise.
 pub trait PrivateIsPrime {
     type Output: Bit;
+    type Witness;
 }
 
 // 0 is not considered prime
 impl PrivateIsPrime for UTerm {
     type Output = False;
+    type Witness = Self;
 }
 
 // 1 is not considered prime
 impl PrivateIsPrime for UInt<UTerm, B1> {
     type Output = False;
+    type Witness = Self;
 }
 
 // Numbers greater than 1 apply a test using trial division by
@@ -42,6 +78,116 @@ impl PrivateIsPrime for UInt<UTerm, B1> {
 impl<U,Ba,Bb> PrivateIsPrime for UInt<UInt<U,Ba>,Bb>
     where Self: Len,
           Length<Self>: CeilDivBy2,
-          Self: TrialDivisionTreeBranch0<CeilDivBy2Out<Length<Self>>, UTerm> {
+          Self: TrialDivisionTreeBranch0<CeilDivBy2Out<Length<Self>>, UTerm>,
+          <Self as TrialDivisionTreeBranch0<CeilDivBy2Out<Length<Self>>, UTerm>>::Witness: ReplaceZero<Self> {
     type Output = <Self as TrialDivisionTreeBranch0<CeilDivBy2Out<Length<Self>>, UTerm>>::Output;
+    type Witness = ReplaceZeroOut<<Self as TrialDivisionTreeBranch0<CeilDivBy2Out<Length<Self>>, UTerm>>::Witness, Self>;
+}
+
+#[test]
+fn test_witness() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, <U0 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U1, <U1 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U2, <U2 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U2, <U4 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U3, <U9 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U7, <U91 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U97, <U97 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U2, <U1024 as PrivateIsPrime>::Witness);
+    assert_type_eq!(U31, <U961 as PrivateIsPrime>::Witness);
 }
+
+
+// Marker traits for readable errors.
+//
+// Each of the following traits is a check that public bounds make on
+// a type computed by a private type operator, such as the result of a
+// primality test. The trait is implemented only for the result that
+// passes the check, so it holds exactly when the equivalent bound on
+// the associated type would. When it fails, though, the compiler
+// reports the `on_unimplemented` message of the trait instead of a
+// type mismatch deep inside the type operator, and the extra type
+// parameters carry the integers that the message names.
+
+
+// Integers greater than 1.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is neither prime nor composite",
+    label = "0 and 1 are neither prime nor composite"
+)]
+pub trait GreaterThanOne {}
+
+impl<U,Ba,Bb> GreaterThanOne for UInt<UInt<U,Ba>,Bb> {}
+
+
+// Self is the result of the primality test of N and is True. W is
+// the primality witness of N, which the message names as a divisor.
+// Since the impl holds for every W, it follows from a bound on the
+// result alone, such as `N: IsPrime<Output=True>`.
+#[diagnostic::on_unimplemented(
+    message = "`{N}` is not prime",
+    label = "`{N}` is divisible by `{W}`",
+    note = "`<N as CompositeWitness>::Divisor` is the smallest prime divisor of a composite `N`"
+)]
+pub trait IsTrueFor<N,W> {}
+
+impl<N,W> IsTrueFor<N,W> for True {}
+
+
+// Self is the result of the primality test of N and is False.
+#[diagnostic::on_unimplemented(
+    message = "`{N}` is prime",
+    label = "expected an integer that is not prime"
+)]
+pub trait IsFalseFor<N> {}
+
+impl<N> IsFalseFor<N> for False {}
+
+
+// Self is the residue of P modulo M and is R, the residue of A
+// modulo M.
+#[diagnostic::on_unimplemented(
+    message = "`{P}` is not congruent to `{A}` modulo `{M}`",
+    label = "expected a prime congruent to `{A}` modulo `{M}`"
+)]
+pub trait SameResidue<P,A,M,R> {}
+
+impl<P,A,M,R> SameResidue<P,A,M,R> for R {}
+
+
+// Self is the result of the equality test of the primes P and Q and
+// is False.
+#[diagnostic::on_unimplemented(
+    message = "`{P}` and `{Q}` are the same prime",
+    label = "expected two distinct primes"
+)]
+pub trait DistinctPrimes<P,Q> {}
+
+impl<P,Q> DistinctPrimes<P,Q> for False {}
+
+
+// Self is the result of the coprimality test of A and B and is True.
+#[diagnostic::on_unimplemented(
+    message = "`{A}` and `{B}` are not coprime",
+    label = "expected pairwise coprime integers"
+)]
+pub trait CoprimePair<A,B> {}
+
+impl<A,B> CoprimePair<A,B> for True {}
+
+
+// Self is the result of the equality test of A and B and is False.
+#[diagnostic::on_unimplemented(
+    message = "`{A}` appears more than once",
+    label = "expected distinct integers"
+)]
+pub trait DistinctPair<A,B> {}
+
+impl<A,B> DistinctPair<A,B> for False {}
+
+
+// Helpers for the assert_prime! and assert_composite! macros.
+pub const fn assert_prime<N: Prime>() {}
+
+pub const fn assert_composite<N: Composite>() {}
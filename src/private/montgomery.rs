@@ -0,0 +1,125 @@
+// src/private/montgomery.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, Shl, Sub};
+
+use typenum::bit::{B0, B1};
+use typenum::consts::{U1, U64};
+use typenum::operator_aliases::{Shleft, Sub1, Sum};
+use typenum::uint::{UInt, UTerm};
+
+use super::reduction::{PushBit, PushBitOut, Reduce, ReduceOut};
+
+
+// A type operator for the low Count bits of -1/P modulo 2^Count,
+// for odd P. Self is the carry C, which starts at 1.
+//
+// The bits are found from the least significant one up. If the
+// partial inverse n satisfies P*n + 1 == C*2^i, the next bit b must
+// make C + b*P even, so b is the low bit of C, and the next carry is
+// (C + b*P)/2. This avoids the wide division that Euclid's algorithm
+// would need for a modulus of 2^64.
+pub trait NegInverseBits<P,Count> {
+    type Output;
+}
+
+impl<C,P> NegInverseBits<P,UTerm> for C {
+    type Output = UTerm;
+}
+
+// The carry is zero, so every remaining bit is zero.
+impl<P,Uc,Bc> NegInverseBits<P,UInt<Uc,Bc>> for UTerm {
+    type Output = UTerm;
+}
+
+// The carry is even, so the bit is 0 and the carry is halved.
+impl<U,P,Uc,Bc> NegInverseBits<P,UInt<Uc,Bc>> for UInt<U,B0>
+    where UInt<Uc,Bc>: Sub<B1>,
+          U: NegInverseBits<P, Sub1<UInt<Uc,Bc>>>,
+          NegInverseBitsOut<U, P, Sub1<UInt<Uc,Bc>>>: PushBit<B0> {
+    type Output = PushBitOut<NegInverseBitsOut<U, P, Sub1<UInt<Uc,Bc>>>, B0>;
+}
+
+// The carry is odd, so the bit is 1 and the carry becomes (C+P)/2.
+impl<U,P,Uc,Bc> NegInverseBits<P,UInt<Uc,Bc>> for UInt<U,B1>
+    where UInt<U,B1>: Add<P>,
+          Sum<UInt<U,B1>,P>: HalfOfEven,
+          UInt<Uc,Bc>: Sub<B1>,
+          HalfOfEvenOut<Sum<UInt<U,B1>,P>>: NegInverseBits<P, Sub1<UInt<Uc,Bc>>>,
+          NegInverseBitsOut<HalfOfEvenOut<Sum<UInt<U,B1>,P>>, P, Sub1<UInt<Uc,Bc>>>: PushBit<B1> {
+    type Output = PushBitOut<NegInverseBitsOut<HalfOfEvenOut<Sum<UInt<U,B1>,P>>, P, Sub1<UInt<Uc,Bc>>>, B1>;
+}
+
+// Alias for the result of NegInverseBits.
+type NegInverseBitsOut<C,P,Count> = <C as NegInverseBits<P,Count>>::Output;
+
+
+// A type operator for dividing an even Self by 2.
+pub trait HalfOfEven {
+    type Output;
+}
+
+impl<U> HalfOfEven for UInt<U,B0> {
+    type Output = U;
+}
+
+// Alias for the result of HalfOfEven.
+type HalfOfEvenOut<N> = <N as HalfOfEven>::Output;
+
+
+// A type operator for the constants of Montgomery multiplication
+// modulo the odd Self, with R == 2^64: R mod Self, R^2 mod Self and
+// -1/Self mod R.
+pub trait PrivateMontgomery {
+    type R;
+    type R2;
+    type NegInv;
+}
+
+impl<U,B> PrivateMontgomery for UInt<U,B>
+    where U1: Shl<U64>,
+          Self: MontgomeryWithRadix<Shleft<U1,U64>, U64> {
+    type R = <Self as MontgomeryWithRadix<Shleft<U1,U64>, U64>>::R;
+    type R2 = <Self as MontgomeryWithRadix<Shleft<U1,U64>, U64>>::R2;
+    type NegInv = <Self as MontgomeryWithRadix<Shleft<U1,U64>, U64>>::NegInv;
+}
+
+
+// The constants of PrivateMontgomery for the radix R == 2^K. Taking R
+// as a parameter keeps the compiler from expanding the reduction of
+// the concrete 2^64 against a modulus that is not yet known.
+pub trait MontgomeryWithRadix<R,K> {
+    type R;
+    type R2;
+    type NegInv;
+}
+
+impl<P,R,K> MontgomeryWithRadix<R,K> for P
+    where R: Reduce<P>,
+          ReduceOut<R,P>: Shl<K>,
+          Shleft<ReduceOut<R,P>, K>: Reduce<P>,
+          U1: NegInverseBits<P,K> {
+    type R = ReduceOut<R,P>;
+    type R2 = ReduceOut<Shleft<ReduceOut<R,P>, K>, P>;
+    type NegInv = NegInverseBitsOut<U1, P, K>;
+}
+
+#[test]
+fn test_montgomery_constants() {
+    use typenum::consts::*;
+    use typenum::marker_traits::Unsigned;
+    assert_type_eq!(U1, NegInverseBitsOut<U1, U3, U2>); // 3*1+1 == 4
+    assert_type_eq!(U5, NegInverseBitsOut<U1, U3, U4>); // 3*5+1 == 16
+    assert_type_eq!(U0, NegInverseBitsOut<U1, U3, U0>);
+    assert_eq!(<U97 as PrivateMontgomery>::R::U64, 61);
+    assert_eq!(<U97 as PrivateMontgomery>::R2::U64, 35);
+    assert_eq!(<U97 as PrivateMontgomery>::NegInv::U64, 6_656_041_676_080_766_047);
+    assert_eq!(<U3 as PrivateMontgomery>::NegInv::U64, 6_148_914_691_236_517_205);
+}
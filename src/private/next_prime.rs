@@ -0,0 +1,107 @@
+// src/private/next_prime.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, Sub};
+
+use typenum::bit::B1;
+use typenum::consts::{False, True, U2};
+use typenum::operator_aliases::{Add1, Diff, Sum};
+
+use super::PrivateIsPrime;
+
+
+// A type operator. The Output is the smallest prime that is not less
+// than Self.
+pub trait PrimeAtLeast {
+    type Output;
+}
+
+impl<N> PrimeAtLeast for N
+    where N: PrivateIsPrime,
+          N: PrimeAtLeastBranch<<N as PrivateIsPrime>::Output> {
+    type Output = <N as PrimeAtLeastBranch<<N as PrivateIsPrime>::Output>>::Output;
+}
+
+
+pub trait PrimeAtLeastBranch<SelfIsPrime> {
+    type Output;
+}
+
+// Self is prime, so the search is over.
+impl<N> PrimeAtLeastBranch<True> for N {
+    type Output = N;
+}
+
+// Self is not prime, so try the next integer.
+impl<N> PrimeAtLeastBranch<False> for N
+    where N: Add<B1>,
+          Add1<N>: PrimeAtLeast {
+    type Output = <Add1<N> as PrimeAtLeast>::Output;
+}
+
+// Alias for the result of PrimeAtLeast.
+pub type PrimeAtLeastOut<N> = <N as PrimeAtLeast>::Output;
+
+#[test]
+fn test_prime_at_least() {
+    use typenum::consts::*;
+    assert_type_eq!(U2, PrimeAtLeastOut<U0>);
+    assert_type_eq!(U2, PrimeAtLeastOut<U2>);
+    assert_type_eq!(U5, PrimeAtLeastOut<U4>);
+    assert_type_eq!(U127, PrimeAtLeastOut<U114>);
+    assert_type_eq!(U1021, PrimeAtLeastOut<U1020>);
+}
+
+
+// A type operator. Self is a prime p. The Output is p-2 if it is
+// prime and otherwise p+2 if it is prime. It is not implemented when
+// neither is prime.
+pub trait PrivateTwinOf {
+    type Output;
+}
+
+impl<P> PrivateTwinOf for P
+    where P: Sub<U2> + Add<U2>,
+          Diff<P,U2>: PrivateIsPrime,
+          Sum<P,U2>: PrivateIsPrime,
+          P: TwinBranch<<Diff<P,U2> as PrivateIsPrime>::Output,
+                        <Sum<P,U2> as PrivateIsPrime>::Output> {
+    type Output = <P as TwinBranch<<Diff<P,U2> as PrivateIsPrime>::Output,
+                                   <Sum<P,U2> as PrivateIsPrime>::Output>>::Output;
+}
+
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a twin prime",
+    label = "neither `{Self}`-2 nor `{Self}`+2 is prime"
+)]
+pub trait TwinBranch<LowerIsPrime, UpperIsPrime> {
+    type Output;
+}
+
+impl<P,UpperIsPrime> TwinBranch<True, UpperIsPrime> for P
+    where P: Sub<U2> {
+    type Output = Diff<P,U2>;
+}
+
+impl<P> TwinBranch<False, True> for P
+    where P: Add<U2> {
+    type Output = Sum<P,U2>;
+}
+
+#[test]
+fn test_twin_of() {
+    use typenum::consts::*;
+    assert_type_eq!(U5, <U3 as PrivateTwinOf>::Output);
+    assert_type_eq!(U3, <U5 as PrivateTwinOf>::Output);
+    assert_type_eq!(U5, <U7 as PrivateTwinOf>::Output);
+    assert_type_eq!(U13, <U11 as PrivateTwinOf>::Output);
+    assert_type_eq!(U1021, <U1019 as PrivateTwinOf>::Output);
+}
@@ -0,0 +1,469 @@
+// src/private/pocklington.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Checking Pocklington certificates of primality.
+//
+// Let N-1 = F*R, where every prime factor q of F is known. If some x
+// has x^(N-1) = 1 modulo N and x^((N-1)/q) - 1 is coprime to N for
+// every q, then every prime divisor of N is 1 modulo F. So if F > R,
+// and hence F > sqrt(N), N is prime.
+//
+// A certificate supplies R, x and, for each q, an inverse of
+// x^((N-1)/q) - 1 to show that it is coprime to N. The exponentiations
+// take a few hundred multiplications modulo N, which is far too slow
+// with typenum's binary integers. Instead, residues are little-endian
+// lists of hexadecimal digits, of a fixed width W with 16^W > 4N, and
+// they are multiplied in Montgomery form, where x is represented by
+// x*16^W modulo N. Any representative below 16^W works, so a value
+// is only brought below N to compare it.
+
+use core::ops::{Div, Mul, Sub};
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::{B0, B1};
+use typenum::consts::{True, U1};
+use typenum::marker_traits::{NonZero, Unsigned};
+use typenum::operator_aliases::{Exp, Prod, Quot, Sub1};
+use typenum::type_operators::{IsGreater, Pow, Same};
+use typenum::uint::{UInt, UTerm};
+
+use CertifiedPrime;
+use super::hex::{Byte, ByteAdd, H0, H1, HexMulAdd, HexMulLow, HexNegInv, HexOfBits, HexSub};
+
+
+// A certificate that N is prime, where N-1 = F*R for the product F of
+// Factors, and X is the Montgomery form of the witness x.
+pub struct Pocklington<X,R,Factors>(X, R, Factors);
+
+// A prime factor of F, which divides it K times. U is the Montgomery
+// form of the inverse of x^((N-1)/Q) - 1.
+pub struct PocklingtonFactor<Q,K,U>(Q, K, U);
+
+
+// Implemented by certificates that N is prime.
+pub trait PrivateCertificate<N> {}
+
+impl<N,X,R,Q,K,U,Fs> PrivateCertificate<N> for Pocklington<X,R,TArr<PocklingtonFactor<Q,K,U>,Fs>>
+    where N: Sub<B1> + Context,
+          R: Unsigned + NonZero,
+          Q: CertifiedPrime,
+          TArr<PocklingtonFactor<Q,K,U>,Fs>: FactoredPart,
+          FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>: Mul<R> + IsGreater<R,Output=True>,
+          Prod<FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>,R>: Same<Sub1<N>>,
+          X: ToResidue<ContextOut<N>>,
+          R: MontPow<ToResidueOut<X,ContextOut<N>>,ContextOut<N>>,
+          TArr<PocklingtonFactor<Q,K,U>,Fs>: CheckFactors<
+              MontPowOut<ToResidueOut<X,ContextOut<N>>,R,ContextOut<N>>,
+              FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>,
+              ContextOut<N>>,
+          // x^(N-1) = (x^((N-1)/Q))^Q, where the inner power is
+          // already needed for the first factor.
+          TArr<PocklingtonFactor<Q,K,U>,Fs>: FirstFactorPower<
+              MontPowOut<ToResidueOut<X,ContextOut<N>>,R,ContextOut<N>>,
+              FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>,
+              ContextOut<N>>,
+          <Q as CertifiedPrime>::Value: MontPow<
+              FirstFactorPowerOut<
+                  TArr<PocklingtonFactor<Q,K,U>,Fs>,
+                  MontPowOut<ToResidueOut<X,ContextOut<N>>,R,ContextOut<N>>,
+                  FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>,
+                  ContextOut<N>>,
+              ContextOut<N>>,
+          MontPowOut<
+              FirstFactorPowerOut<
+                  TArr<PocklingtonFactor<Q,K,U>,Fs>,
+                  MontPowOut<ToResidueOut<X,ContextOut<N>>,R,ContextOut<N>>,
+                  FactoredPartOut<TArr<PocklingtonFactor<Q,K,U>,Fs>>,
+                  ContextOut<N>>,
+              <Q as CertifiedPrime>::Value,
+              ContextOut<N>>: IsOneMod<ContextOut<N>> {}
+
+
+// F, the product of the factors raised to their multiplicities.
+pub trait FactoredPart {
+    type Output;
+}
+
+pub type FactoredPartOut<Fs> = <Fs as FactoredPart>::Output;
+
+impl FactoredPart for ATerm {
+    type Output = U1;
+}
+
+impl<Q,K,U,Fs> FactoredPart for TArr<PocklingtonFactor<Q,K,U>,Fs>
+    where Q: CertifiedPrime,
+          K: Unsigned + NonZero,
+          <Q as CertifiedPrime>::Value: Pow<K>,
+          Fs: FactoredPart,
+          Exp<<Q as CertifiedPrime>::Value,K>: Mul<FactoredPartOut<Fs>> {
+    type Output = Prod<Exp<<Q as CertifiedPrime>::Value,K>,FactoredPartOut<Fs>>;
+}
+
+
+// B^(F/Q) for the first factor Q, where B = x^R.
+pub trait FirstFactorPower<B,F,M> {
+    type Output;
+}
+
+pub type FirstFactorPowerOut<Fs,B,F,M> = <Fs as FirstFactorPower<B,F,M>>::Output;
+
+impl<Q,K,U,Fs,B,F,M> FirstFactorPower<B,F,M> for TArr<PocklingtonFactor<Q,K,U>,Fs>
+    where Q: CertifiedPrime,
+          F: Div<<Q as CertifiedPrime>::Value>,
+          Quot<F,<Q as CertifiedPrime>::Value>: MontPow<B,M> {
+    type Output = MontPowOut<B,Quot<F,<Q as CertifiedPrime>::Value>,M>;
+}
+
+
+// Checks that B^(F/Q) - 1 is invertible modulo N for every factor Q,
+// where B = x^R, by multiplying it with the inverse U.
+pub trait CheckFactors<B,F,M> {}
+
+impl<B,F,M> CheckFactors<B,F,M> for ATerm {}
+
+impl<Q,K,U,Fs,B,F,N,NInv,Z,One,D> CheckFactors<B,F,Mont<N,NInv,Z,One>>
+    for TArr<PocklingtonFactor<Q,K,U>,Fs>
+    where TArr<PocklingtonFactor<Q,K,U>,Fs>: FirstFactorPower<B,F,Mont<N,NInv,Z,One>>,
+          FirstFactorPowerOut<TArr<PocklingtonFactor<Q,K,U>,Fs>,B,F,Mont<N,NInv,Z,One>>:
+              FromMont<Mont<N,NInv,Z,One>>,
+          FromMontOut<FirstFactorPowerOut<TArr<PocklingtonFactor<Q,K,U>,Fs>,B,F,Mont<N,NInv,Z,One>>,
+                      Mont<N,NInv,Z,One>>: SubList<One,B0,Output=(B0,D)>,
+          U: ToResidue<Mont<N,NInv,Z,One>>,
+          D: MontMul<ToResidueOut<U,Mont<N,NInv,Z,One>>,Mont<N,NInv,Z,One>>,
+          // D is an ordinary residue, so the product is one too.
+          MontMulOut<D,ToResidueOut<U,Mont<N,NInv,Z,One>>,Mont<N,NInv,Z,One>>: Canon<N>,
+          CanonOut<MontMulOut<D,ToResidueOut<U,Mont<N,NInv,Z,One>>,Mont<N,NInv,Z,One>>,N>:
+              Same<One>,
+          Fs: CheckFactors<B,F,Mont<N,NInv,Z,One>> {}
+
+
+// The modulus N, its negated inverse modulo 16, zero and one, each
+// as W digits.
+pub struct Mont<N,NInv,Z,One>(N, NInv, Z, One);
+
+// The Montgomery context of Self.
+pub trait Context {
+    type Output;
+}
+
+pub type ContextOut<N> = <N as Context>::Output;
+
+impl<N> Context for N
+    where N: ToHex,
+          UInt<UInt<N,B0>,B0>: ToHex,
+          ToHexOut<N>: PadTo<ToHexOut<UInt<UInt<N,B0>,B0>>>,
+          PadToOut<ToHexOut<N>,ToHexOut<UInt<UInt<N,B0>,B0>>>: ContextOfDigits {
+    type Output = <PadToOut<ToHexOut<N>,ToHexOut<UInt<UInt<N,B0>,B0>>> as ContextOfDigits>::Output;
+}
+
+pub trait ContextOfDigits {
+    type Output;
+}
+
+impl<D,Ds> ContextOfDigits for TArr<D,Ds>
+    where D: HexNegInv,
+          Ds: Zeros,
+          TArr<H1,ATerm>: PadTo<TArr<D,Ds>> {
+    type Output = Mont<TArr<D,Ds>,
+                       <D as HexNegInv>::Output,
+                       TArr<H0,ZerosOut<Ds>>,
+                       PadToOut<TArr<H1,ATerm>,TArr<D,Ds>>>;
+}
+
+
+// The digits of Self, a typenum integer, as a residue of the context.
+pub trait ToResidue<M> {
+    type Output;
+}
+
+pub type ToResidueOut<X,M> = <X as ToResidue<M>>::Output;
+
+impl<X,N,NInv,Z,One> ToResidue<Mont<N,NInv,Z,One>> for X
+    where X: ToHex,
+          ToHexOut<X>: PadTo<N> {
+    type Output = PadToOut<ToHexOut<X>,N>;
+}
+
+
+// Checks that Self, in Montgomery form, is 1 modulo N.
+pub trait IsOneMod<M> {}
+
+impl<V,N,NInv,Z,One> IsOneMod<Mont<N,NInv,Z,One>> for V
+    where V: FromMont<Mont<N,NInv,Z,One>>,
+          FromMontOut<V,Mont<N,NInv,Z,One>>: Same<One> {}
+
+
+// Self, in Montgomery form, as an ordinary residue. The result is
+// below N as long as Self is below 2N.
+pub trait FromMont<M> {
+    type Output;
+}
+
+pub type FromMontOut<V,M> = <V as FromMont<M>>::Output;
+
+impl<V,N,NInv,Z,One> FromMont<Mont<N,NInv,Z,One>> for V
+    where V: MontMul<One,Mont<N,NInv,Z,One>>,
+          MontMulOut<V,One,Mont<N,NInv,Z,One>>: Canon<N> {
+    type Output = CanonOut<MontMulOut<V,One,Mont<N,NInv,Z,One>>,N>;
+}
+
+
+// X^Self in Montgomery form, for nonzero Self. The exponent is Self
+// so that the recursion stops while it is still unknown.
+pub trait MontPow<X,M> {
+    type Output;
+}
+
+pub type MontPowOut<X,E,M> = <E as MontPow<X,M>>::Output;
+
+impl<X,M> MontPow<X,M> for UInt<UTerm,B1> {
+    type Output = X;
+}
+
+impl<X,U,Ba,Bb,M> MontPow<X,M> for UInt<UInt<U,Ba>,Bb>
+    where UInt<U,Ba>: MontPow<X,M>,
+          MontPowOut<X,UInt<U,Ba>,M>: MontMul<MontPowOut<X,UInt<U,Ba>,M>,M>,
+          MontMulOut<MontPowOut<X,UInt<U,Ba>,M>,MontPowOut<X,UInt<U,Ba>,M>,M>: MulIfSet<Bb,X,M> {
+    type Output = <MontMulOut<MontPowOut<X,UInt<U,Ba>,M>,MontPowOut<X,UInt<U,Ba>,M>,M>
+                   as MulIfSet<Bb,X,M>>::Output;
+}
+
+// Self*X if the bit is set, and Self otherwise.
+pub trait MulIfSet<Bit,X,M> {
+    type Output;
+}
+
+impl<P,X,M> MulIfSet<B0,X,M> for P {
+    type Output = P;
+}
+
+impl<P,X,M> MulIfSet<B1,X,M> for P where P: MontMul<X,M> {
+    type Output = MontMulOut<P,X,M>;
+}
+
+
+// Self*Y/16^W modulo N. The product is reduced one digit of Y at a
+// time, adding the multiple of N that clears the lowest digit before
+// dropping it. For Self and Y below 2N, the result is below 2N.
+pub trait MontMul<Y,M> {
+    type Output;
+}
+
+pub type MontMulOut<X,Y,M> = <X as MontMul<Y,M>>::Output;
+
+impl<X,Y,N,NInv,Z,One> MontMul<Y,Mont<N,NInv,Z,One>> for X
+    where Z: MontRound<X,Mont<N,NInv,Z,One>,Y> {
+    type Output = <Z as MontRound<X,Mont<N,NInv,Z,One>,Y>>::Output;
+}
+
+// Self, the accumulator, after the rounds for the digits Y of the
+// multiplier.
+pub trait MontRound<X,M,Y> {
+    type Output;
+}
+
+impl<T,X,M> MontRound<X,M,ATerm> for T {
+    type Output = T;
+}
+
+impl<T,X,M,Y,Ys> MontRound<X,M,TArr<Y,Ys>> for T
+    where X: MacRow<Y,T,Extend>,
+          MacRowOut<X,Y,T,Extend>: MontReduce<X,M,Ys> {
+    type Output = <MacRowOut<X,Y,T,Extend> as MontReduce<X,M,Ys>>::Output;
+}
+
+pub trait MontReduce<X,M,Ys> {
+    type Output;
+}
+
+impl<T0,Ts,X,N,NInv,Z,One,Ys> MontReduce<X,Mont<N,NInv,Z,One>,Ys> for TArr<T0,Ts>
+    where T0: HexMulLow<NInv>,
+          N: MacRow<<T0 as HexMulLow<NInv>>::Output,TArr<T0,Ts>,Exact>,
+          MacRowOut<N,<T0 as HexMulLow<NInv>>::Output,TArr<T0,Ts>,Exact>:
+              MontShift<X,Mont<N,NInv,Z,One>,Ys> {
+    type Output = <MacRowOut<N,<T0 as HexMulLow<NInv>>::Output,TArr<T0,Ts>,Exact>
+                   as MontShift<X,Mont<N,NInv,Z,One>,Ys>>::Output;
+}
+
+// Drops the lowest digit, which the reduction cleared.
+pub trait MontShift<X,M,Ys> {
+    type Output;
+}
+
+impl<Ts,X,M,Ys> MontShift<X,M,Ys> for TArr<H0,Ts> where Ts: MontRound<X,M,Ys> {
+    type Output = <Ts as MontRound<X,M,Ys>>::Output;
+}
+
+
+// Self*D + Acc, for a list Self and a digit D. In Extend mode, the
+// result has a digit more than Acc, and in Exact mode, that digit
+// must be zero and is left out.
+pub struct Extend;
+pub struct Exact;
+
+pub trait MacRow<D,Acc,Mode> {
+    type Output;
+}
+
+pub type MacRowOut<X,D,Acc,Mode> = <X as MacRow<D,Acc,Mode>>::Output;
+
+impl<X,Xs,D,A,As,Mode> MacRow<D,TArr<A,As>,Mode> for TArr<X,Xs>
+    where X: HexMulAdd<D,A>,
+          Xs: MacCarry<D,As,<X as HexMulAdd<D,A>>::Output,Mode> {
+    type Output = <Xs as MacCarry<D,As,<X as HexMulAdd<D,A>>::Output,Mode>>::Output;
+}
+
+// The rest of MacRow, where the previous digit gave Byte<C,L>: L is
+// the next digit of the result and C is carried.
+pub trait MacCarry<D,Acc,P,Mode> {
+    type Output;
+}
+
+impl<D,C,L> MacCarry<D,ATerm,Byte<C,L>,Extend> for ATerm {
+    type Output = TArr<L,TArr<C,ATerm>>;
+}
+
+impl<D,L> MacCarry<D,ATerm,Byte<H0,L>,Exact> for ATerm {
+    type Output = TArr<L,ATerm>;
+}
+
+impl<D,A,As,C,L,Mode> MacCarry<D,TArr<A,As>,Byte<C,L>,Mode> for ATerm
+    where Byte<H0,A>: ByteAdd<C>,
+          ATerm: MacCarry<D,As,<Byte<H0,A> as ByteAdd<C>>::Output,Mode> {
+    type Output = TArr<L,<ATerm as MacCarry<D,As,<Byte<H0,A> as ByteAdd<C>>::Output,Mode>>::Output>;
+}
+
+impl<X,Xs,D,A,As,C,L,Mode> MacCarry<D,TArr<A,As>,Byte<C,L>,Mode> for TArr<X,Xs>
+    where X: HexMulAdd<D,A>,
+          <X as HexMulAdd<D,A>>::Output: ByteAdd<C>,
+          Xs: MacCarry<D,As,<<X as HexMulAdd<D,A>>::Output as ByteAdd<C>>::Output,Mode> {
+    type Output = TArr<L,<Xs as MacCarry<D,As,<<X as HexMulAdd<D,A>>::Output as ByteAdd<C>>::Output,
+                                         Mode>>::Output>;
+}
+
+
+// Self - Y - Borrow for lists of the same length, as the borrow out
+// and the difference.
+pub trait SubList<Y,Borrow> {
+    type Output;
+}
+
+impl<Bw> SubList<ATerm,Bw> for ATerm {
+    type Output = (Bw, ATerm);
+}
+
+impl<X,Xs,Y,Ys,Bw> SubList<TArr<Y,Ys>,Bw> for TArr<X,Xs>
+    where X: HexSub<Y,Bw>,
+          Xs: SubRest<Ys,<X as HexSub<Y,Bw>>::Output> {
+    type Output = <Xs as SubRest<Ys,<X as HexSub<Y,Bw>>::Output>>::Output;
+}
+
+pub trait SubRest<Y,P> {
+    type Output;
+}
+
+impl<Xs,Ys,Bw,D,Bo,Ds> SubRest<Ys,(Bw,D)> for Xs where Xs: SubList<Ys,Bw,Output=(Bo,Ds)> {
+    type Output = (Bo, TArr<D,Ds>);
+}
+
+
+// Self modulo N, for Self below 2N.
+pub trait Canon<N> {
+    type Output;
+}
+
+pub type CanonOut<T,N> = <T as Canon<N>>::Output;
+
+impl<T,N> Canon<N> for T
+    where T: SubList<N,B0>,
+          <T as SubList<N,B0>>::Output: CanonPick<T> {
+    type Output = <<T as SubList<N,B0>>::Output as CanonPick<T>>::Output;
+}
+
+// Self if the subtraction borrowed, and the difference otherwise.
+pub trait CanonPick<T> {
+    type Output;
+}
+
+impl<T,D> CanonPick<T> for (B1,D) {
+    type Output = T;
+}
+
+impl<T,D> CanonPick<T> for (B0,D) {
+    type Output = D;
+}
+
+
+// A list of zeros as long as Self.
+pub trait Zeros {
+    type Output;
+}
+
+pub type ZerosOut<T> = <T as Zeros>::Output;
+
+impl Zeros for ATerm {
+    type Output = ATerm;
+}
+
+impl<D,Ds> Zeros for TArr<D,Ds> where Ds: Zeros {
+    type Output = TArr<H0,ZerosOut<Ds>>;
+}
+
+
+// Self extended with zeros to the length of T, which is no shorter.
+pub trait PadTo<T> {
+    type Output;
+}
+
+pub type PadToOut<X,T> = <X as PadTo<T>>::Output;
+
+impl PadTo<ATerm> for ATerm {
+    type Output = ATerm;
+}
+
+impl<T,Ts> PadTo<TArr<T,Ts>> for ATerm where ATerm: PadTo<Ts> {
+    type Output = TArr<H0,PadToOut<ATerm,Ts>>;
+}
+
+impl<D,Ds,T,Ts> PadTo<TArr<T,Ts>> for TArr<D,Ds> where Ds: PadTo<Ts> {
+    type Output = TArr<D,PadToOut<Ds,Ts>>;
+}
+
+
+// The hexadecimal digits of a typenum integer, least significant
+// first.
+pub trait ToHex {
+    type Output;
+}
+
+pub type ToHexOut<N> = <N as ToHex>::Output;
+
+impl ToHex for UTerm {
+    type Output = ATerm;
+}
+
+impl<A> ToHex for UInt<UTerm,A> where (B0,B0,B0,A): HexOfBits {
+    type Output = TArr<<(B0,B0,B0,A) as HexOfBits>::Output,ATerm>;
+}
+
+impl<B,A> ToHex for UInt<UInt<UTerm,B>,A> where (B0,B0,B,A): HexOfBits {
+    type Output = TArr<<(B0,B0,B,A) as HexOfBits>::Output,ATerm>;
+}
+
+impl<C,B,A> ToHex for UInt<UInt<UInt<UTerm,C>,B>,A> where (B0,C,B,A): HexOfBits {
+    type Output = TArr<<(B0,C,B,A) as HexOfBits>::Output,ATerm>;
+}
+
+impl<U,D,C,B,A> ToHex for UInt<UInt<UInt<UInt<U,D>,C>,B>,A>
+    where (D,C,B,A): HexOfBits,
+          U: ToHex {
+    type Output = TArr<<(D,C,B,A) as HexOfBits>::Output,ToHexOut<U>>;
+}
+
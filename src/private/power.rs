@@ -0,0 +1,73 @@
+// src/private/power.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::Mul;
+
+use typenum::bit::{B0, B1};
+use typenum::operator_aliases::Prod;
+use typenum::uint::{UInt, UTerm};
+
+use super::reduction::{Reduce, ReduceOut};
+
+
+// A type operator for modular exponentiation. The Output is Self
+// raised to the power E, reduced modulo M.
+//
+// The exponent is consumed from its least significant bit, so the
+// recursion is square-and-multiply from the most significant bit:
+//     b^(2*e+0) == (b^e)^2
+//     b^(2*e+1) == (b^e)^2 * b
+pub trait PrivateModPow<E,M> {
+    type Output;
+}
+
+// b^0 == 1, reduced so that the result is 0 modulo 1.
+impl<B,M> PrivateModPow<UTerm,M> for B
+    where UInt<UTerm,B1>: Reduce<M> {
+    type Output = ReduceOut<UInt<UTerm,B1>,M>;
+}
+
+impl<B,Ue,M> PrivateModPow<UInt<Ue,B0>,M> for B
+    where B: PrivateModPow<Ue,M>,
+          ModPowOut<B,Ue,M>: Mul<ModPowOut<B,Ue,M>>,
+          Prod<ModPowOut<B,Ue,M>, ModPowOut<B,Ue,M>>: Reduce<M> {
+    type Output = ModSquareOut<B,Ue,M>;
+}
+
+impl<B,Ue,M> PrivateModPow<UInt<Ue,B1>,M> for B
+    where B: PrivateModPow<Ue,M>,
+          B: Reduce<M>,
+          ModPowOut<B,Ue,M>: Mul<ModPowOut<B,Ue,M>>,
+          Prod<ModPowOut<B,Ue,M>, ModPowOut<B,Ue,M>>: Reduce<M>,
+          ModSquareOut<B,Ue,M>: Mul<ReduceOut<B,M>>,
+          Prod<ModSquareOut<B,Ue,M>, ReduceOut<B,M>>: Reduce<M> {
+    type Output = ReduceOut<Prod<ModSquareOut<B,Ue,M>, ReduceOut<B,M>>, M>;
+}
+
+// (b^e)^2 reduced modulo m
+type ModSquareOut<B,E,M> = ReduceOut<Prod<ModPowOut<B,E,M>, ModPowOut<B,E,M>>, M>;
+
+// Alias for the result of ModPow.
+pub type ModPowOut<B,E,M> = <B as PrivateModPow<E,M>>::Output;
+
+#[test]
+fn test_mod_pow() {
+    use typenum::consts::*;
+    assert_type_eq!(U0, ModPowOut<U5,U0,U1>);
+    assert_type_eq!(U1, ModPowOut<U5,U0,U7>);
+    assert_type_eq!(U0, ModPowOut<U0,U3,U7>);
+    assert_type_eq!(U5, ModPowOut<U5,U1,U7>);
+    assert_type_eq!(U4, ModPowOut<U2,U2,U7>);
+    assert_type_eq!(U1, ModPowOut<U2,U3,U7>);
+    assert_type_eq!(U1, ModPowOut<U10,U6,U7>);
+    assert_type_eq!(U16, ModPowOut<U3,U8,U17>);
+    assert_type_eq!(U1, ModPowOut<U12,U100,U101>);
+    assert_type_eq!(U40, ModPowOut<U12,U37,U101>);
+}
@@ -0,0 +1,158 @@
+// src/private/primitive_root.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, BitAnd, Div, Not, Sub};
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::{Bit, B1};
+use typenum::consts::{False, True};
+use typenum::operator_aliases::{Add1, And, Quot, Sub1};
+use typenum::uint::{UInt, UTerm};
+
+use super::PrivateIsPrime;
+use super::misc::{IsOne, IsOneOut};
+use super::power::{PrivateModPow, ModPowOut};
+
+
+// A type operator. The Output is a type-level array of the prime
+// factors of Self, smallest first, repeated according to their
+// multiplicity. Self must not be zero.
+pub trait PrimeFactors {
+    type Output;
+}
+
+// Split off the smallest prime divisor found by trial division. The
+// witness of 1 is 1 itself, which ends the array.
+impl<N> PrimeFactors for N
+    where N: PrivateIsPrime,
+          N: SplitFactor<<N as PrivateIsPrime>::Witness> {
+    type Output = <N as SplitFactor<<N as PrivateIsPrime>::Witness>>::Output;
+}
+
+
+// A type operator. The Output is the array of prime factors of Self,
+// given its smallest prime divisor D, or given 1 if Self is 1.
+pub trait SplitFactor<D> {
+    type Output;
+}
+
+impl<N> SplitFactor<UInt<UTerm,B1>> for N {
+    type Output = ATerm;
+}
+
+impl<N,U,Ba,Bb> SplitFactor<UInt<UInt<U,Ba>,Bb>> for N
+    where N: Div<UInt<UInt<U,Ba>,Bb>>,
+          Quot<N, UInt<UInt<U,Ba>,Bb>>: PrimeFactors {
+    type Output = TArr<UInt<UInt<U,Ba>,Bb>, PrimeFactorsOut<Quot<N, UInt<UInt<U,Ba>,Bb>>>>;
+}
+
+// Alias for the result of PrimeFactors.
+pub type PrimeFactorsOut<N> = <N as PrimeFactors>::Output;
+
+#[test]
+fn test_prime_factors() {
+    use typenum::consts::*;
+    assert_type_eq!(tarr![], PrimeFactorsOut<U1>);
+    assert_type_eq!(tarr![U2], PrimeFactorsOut<U2>);
+    assert_type_eq!(tarr![U2,U2,U2,U3], PrimeFactorsOut<U24>);
+    assert_type_eq!(tarr![U7,U13], PrimeFactorsOut<U91>);
+    assert_type_eq!(tarr![U97], PrimeFactorsOut<U97>);
+}
+
+
+// A type operator. The Output is True if Self is a generator of the
+// multiplicative group modulo the prime P. Order is P-1 and Factors
+// is a list of the prime factors of Order.
+//
+// Self is a generator when Self^(Order/q) is not 1 for every prime
+// factor q of Order.
+pub trait IsGenerator<P,Order,Factors> {
+    type Output: Bit;
+}
+
+impl<G,P,Order> IsGenerator<P,Order,ATerm> for G {
+    type Output = True;
+}
+
+impl<G,P,Order,Q,Qs> IsGenerator<P,Order,TArr<Q,Qs>> for G
+    where Order: Div<Q>,
+          G: PrivateModPow<Quot<Order,Q>, P>,
+          ModPowOut<G, Quot<Order,Q>, P>: IsOne,
+          IsOneOut<ModPowOut<G, Quot<Order,Q>, P>>: Not,
+          <IsOneOut<ModPowOut<G, Quot<Order,Q>, P>> as Not>::Output: BitAnd<IsGeneratorOut<G,P,Order,Qs>>,
+          G: IsGenerator<P,Order,Qs>,
+          And<<IsOneOut<ModPowOut<G, Quot<Order,Q>, P>> as Not>::Output, IsGeneratorOut<G,P,Order,Qs>>: Bit {
+    type Output = And<<IsOneOut<ModPowOut<G, Quot<Order,Q>, P>> as Not>::Output, IsGeneratorOut<G,P,Order,Qs>>;
+}
+
+// Alias for the result of IsGenerator.
+type IsGeneratorOut<G,P,Order,Factors> = <G as IsGenerator<P,Order,Factors>>::Output;
+
+
+// A type operator. The Output is the smallest generator of the
+// multiplicative group modulo the prime P that is not less than Self.
+// Order is P-1 and Factors is a list of the prime factors of Order.
+pub trait FindGenerator<P,Order,Factors> {
+    type Output;
+}
+
+impl<G,P,Order,Factors> FindGenerator<P,Order,Factors> for G
+    where G: IsGenerator<P,Order,Factors>,
+          G: FindGeneratorBranch<P,Order,Factors,IsGeneratorOut<G,P,Order,Factors>> {
+    type Output = <G as FindGeneratorBranch<P,Order,Factors,IsGeneratorOut<G,P,Order,Factors>>>::Output;
+}
+
+
+pub trait FindGeneratorBranch<P,Order,Factors,SelfIsGenerator> {
+    type Output;
+}
+
+// Self is a generator, so the search is over.
+impl<G,P,Order,Factors> FindGeneratorBranch<P,Order,Factors,True> for G {
+    type Output = G;
+}
+
+// Self is not a generator, so try the next integer.
+impl<G,P,Order,Factors> FindGeneratorBranch<P,Order,Factors,False> for G
+    where G: Add<B1>,
+          Add1<G>: FindGenerator<P,Order,Factors> {
+    type Output = <Add1<G> as FindGenerator<P,Order,Factors>>::Output;
+}
+
+// Alias for the result of FindGenerator.
+pub type FindGeneratorOut<G,P,Order,Factors> = <G as FindGenerator<P,Order,Factors>>::Output;
+
+
+// A type operator for the smallest primitive root of Self, which must
+// be prime. The search for a generator starts at 1, which is the
+// primitive root of 2.
+pub trait PrivatePrimitiveRoot {
+    type Output;
+}
+
+impl<U,B> PrivatePrimitiveRoot for UInt<U,B>
+    where Self: Sub<B1>,
+          Sub1<Self>: PrimeFactors,
+          UInt<UTerm,B1>: FindGenerator<Self, Sub1<Self>, PrimeFactorsOut<Sub1<Self>>> {
+    type Output = FindGeneratorOut<UInt<UTerm,B1>, Self, Sub1<Self>, PrimeFactorsOut<Sub1<Self>>>;
+}
+
+#[test]
+fn test_primitive_root() {
+    use typenum::consts::*;
+    assert_type_eq!(U1, <U2 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U2, <U3 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U2, <U5 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U3, <U7 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U3, <U17 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U6, <U41 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U5, <U97 as PrivatePrimitiveRoot>::Output);
+    assert_type_eq!(U3, <U257 as PrivatePrimitiveRoot>::Output);
+}
@@ -47,7 +47,7 @@ impl<B> PushBit<B> for Underflowed {
 }
 
 // Alias for the result of PushBit<B>.
-type PushBitOut<U,B> = <U as PushBit<B>>::Output;
+pub type PushBitOut<U,B> = <U as PushBit<B>>::Output;
 
 
 // A type operator. If Self is greater or equal to RHS, the Output is
@@ -85,7 +85,7 @@ impl<Ul,Ur> TrySub<UInt<Ur,B1>> for UInt<Ul,B0>
 }
 
 // Alias for the result of TrySub.
-type TrySubOut<L,R> = <L as TrySub<R>>::Output;
+pub type TrySubOut<L,R> = <L as TrySub<R>>::Output;
 
 
 // A type operator. If Self is a type-level integer, the Output is
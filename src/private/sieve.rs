@@ -0,0 +1,119 @@
+// src/private/sieve.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+// Listing the primes up to a limit walks the same kind of binary tree
+// as trial division, with leaves labeled by the integers built from
+// the path down the tree. Walking the tree rather than counting down
+// from the limit keeps the recursion depth proportional to the number
+// of bits in the limit.
+//
+// The tree is walked right to left, 1 branch first, and each prime
+// leaf is prepended to an accumulated array, so that the array ends
+// up in increasing order.
+
+use core::ops::Sub;
+
+use typenum::array::{ATerm, TArr};
+use typenum::bit::B1;
+use typenum::consts::{False, True};
+use typenum::operator_aliases::{LeEq, Length, Sub1};
+use typenum::type_operators::{IsLessOrEqual, Len};
+use typenum::uint::{UInt, UTerm};
+
+use super::PrivateIsPrime;
+use super::misc::{Double, DoubleOut};
+
+
+// A type operator. Self is an array of primes, smallest first. If
+// DepthCount is zero, prepend the accumulated Prefix if it is a prime
+// no greater than Limit. If DepthCount is positive, decrement it and
+// walk the 1 branch, then the 0 branch.
+pub trait SieveTree<DepthCount, Prefix, Limit> {
+    type Output;
+}
+
+impl<A,P,L> SieveTree<UTerm,P,L> for A
+    where P: IsLessOrEqual<L>,
+          A: SieveLeaf<P, LeEq<P,L>> {
+    type Output = <A as SieveLeaf<P, LeEq<P,L>>>::Output;
+}
+
+impl<A,Uc,Bc,P,L> SieveTree<UInt<Uc,Bc>,P,L> for A
+    where UInt<Uc,Bc>: Sub<B1>,
+          P: Double,
+          A: SieveTree<Sub1<UInt<Uc,Bc>>, UInt<P,B1>, L>,
+          SieveTreeOut<A, Sub1<UInt<Uc,Bc>>, UInt<P,B1>, L>: SieveTree<Sub1<UInt<Uc,Bc>>, DoubleOut<P>, L> {
+    type Output = SieveTreeOut<SieveTreeOut<A, Sub1<UInt<Uc,Bc>>, UInt<P,B1>, L>, Sub1<UInt<Uc,Bc>>, DoubleOut<P>, L>;
+}
+
+// Alias for the result of SieveTree.
+type SieveTreeOut<A,C,P,L> = <A as SieveTree<C,P,L>>::Output;
+
+
+// A type operator. The Output is Self with P prepended if P is prime.
+// The primality test is skipped entirely when InRange is False.
+pub trait SieveLeaf<P,InRange> {
+    type Output;
+}
+
+impl<A,P> SieveLeaf<P,False> for A {
+    type Output = A;
+}
+
+impl<A,P> SieveLeaf<P,True> for A
+    where P: PrivateIsPrime,
+          A: PushIf<<P as PrivateIsPrime>::Output, P> {
+    type Output = PushIfOut<A, <P as PrivateIsPrime>::Output, P>;
+}
+
+
+// A type operator. The Output is Self with V prepended if C is True,
+// and Self unchanged if C is False.
+pub trait PushIf<C,V> {
+    type Output;
+}
+
+impl<A,V> PushIf<True,V> for A {
+    type Output = TArr<V,A>;
+}
+
+impl<A,V> PushIf<False,V> for A {
+    type Output = A;
+}
+
+// Alias for the result of PushIf.
+type PushIfOut<A,C,V> = <A as PushIf<C,V>>::Output;
+
+
+// A type operator. The Output is the array of primes no greater than
+// Self, smallest first.
+pub trait PrivatePrimesUpTo {
+    type Output;
+}
+
+impl PrivatePrimesUpTo for UTerm {
+    type Output = ATerm;
+}
+
+impl<U,B> PrivatePrimesUpTo for UInt<U,B>
+    where Self: Len,
+          ATerm: SieveTree<Length<Self>, UTerm, Self> {
+    type Output = SieveTreeOut<ATerm, Length<Self>, UTerm, Self>;
+}
+
+#[test]
+fn test_primes_up_to() {
+    use typenum::consts::*;
+    assert_type_eq!(tarr![], <U0 as PrivatePrimesUpTo>::Output);
+    assert_type_eq!(tarr![], <U1 as PrivatePrimesUpTo>::Output);
+    assert_type_eq!(tarr![U2], <U2 as PrivatePrimesUpTo>::Output);
+    assert_type_eq!(tarr![U2, U3, U5, U7], <U10 as PrivatePrimesUpTo>::Output);
+    assert_type_eq!(tarr![U2, U3, U5, U7, U11, U13], <U13 as PrivatePrimesUpTo>::Output);
+}
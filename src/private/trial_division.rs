@@ -15,7 +15,7 @@ use typenum::consts::{True, False};
 use typenum::operator_aliases::Sub1;
 use typenum::uint::{UInt, UTerm};
 
-use super::misc::{Double, DoubleOut, IsNonZero, IsNonZeroOut};
+use super::misc::{Double, DoubleOut, IsNonZero, IsNonZeroOut, DivisorIfZero, DivisorIfZeroOut};
 use super::reduction::{Reduce, ReduceOut};
 use super::PrivateIsPrime;
 
@@ -44,18 +44,24 @@ use super::PrivateIsPrime;
 
 // A type operator to compute whether Self is divisible by Divisor.
 // The test is skipped entirely when DivisorIsPrime is False.
+//
+// The Witness is Divisor when it divides Self and UTerm otherwise.
 pub trait TrialDivisionTreeLeaf<Divisor,DivisorIsPrime> {
     type Output: Bit;
+    type Witness;
 }
 
 impl<N,D> TrialDivisionTreeLeaf<D,False> for N {
     type Output = True;
+    type Witness = UTerm;
 }
 
 impl<N,D> TrialDivisionTreeLeaf<D,True> for N
     where N: Reduce<D>,
-          ReduceOut<N,D>: IsNonZero {
+          ReduceOut<N,D>: IsNonZero,
+          ReduceOut<N,D>: DivisorIfZero<D> {
     type Output = IsNonZeroOut<ReduceOut<N,D>>;
+    type Witness = DivisorIfZeroOut<ReduceOut<N,D>, D>;
 }
 
 
@@ -63,8 +69,12 @@ impl<N,D> TrialDivisionTreeLeaf<D,True> for N
 // accumulated Divisor is prime and call TrialDivisionLeaf. If
 // DepthCount is positive, decrement it and try the 0 branch, then the
 // 1 branch.
+//
+// The Witness is the first divisor of Self found by the search, or
+// UTerm if there is none.
 pub trait TrialDivisionTreeBranch0<DepthCount, Divisor> {
     type Output: Bit;
+    type Witness;
 }
 
 // the case when Depth is zero: test the divisor for primality and
@@ -73,40 +83,47 @@ impl<D,N> TrialDivisionTreeBranch0<UTerm, D> for N
     where D: PrivateIsPrime,
           N: TrialDivisionTreeLeaf<D, <D as PrivateIsPrime>::Output> {
     type Output = <N as TrialDivisionTreeLeaf<D, <D as PrivateIsPrime>::Output>>::Output;
+    type Witness = <N as TrialDivisionTreeLeaf<D, <D as PrivateIsPrime>::Output>>::Witness;
 }
 
 
 // the case when Depth is nonzero: Decrement the depth, call
 // TrialDivisionTreeBranch0 with a 0 bit appended to the divisor, and
-// send the result to TrialDivisionTreeBranch1. It will use the result
-// of the branch0 to either short-circuit the search or to descend
-// with a 1 bit appended to the divisor.
+// send the witness to TrialDivisionTreeBranch1. It will use the
+// witness from the branch0 to either short-circuit the search or to
+// descend with a 1 bit appended to the divisor.
 impl<N,Uc,Bc,D> TrialDivisionTreeBranch0<UInt<Uc,Bc>,D> for N
     where UInt<Uc,Bc>: Sub<B1>,
           D: Double,
           N: TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>,
           N: TrialDivisionTreeBranch1<Sub1<UInt<Uc,Bc>>,
                                       UInt<D,B1>,
-                                      <N as TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>>::Output> {
+                                      <N as TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>>::Witness> {
     type Output = <N as TrialDivisionTreeBranch1<Sub1<UInt<Uc,Bc>>,
                                                  UInt<D,B1>,
-                                                 <N as TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>>::Output>>::Output;
+                                                 <N as TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>>::Witness>>::Output;
+    type Witness = <N as TrialDivisionTreeBranch1<Sub1<UInt<Uc,Bc>>,
+                                                  UInt<D,B1>,
+                                                  <N as TrialDivisionTreeBranch0<Sub1<UInt<Uc,Bc>>, DoubleOut<D>>>::Witness>>::Witness;
 }
 
 
-pub trait TrialDivisionTreeBranch1<DepthCount, Divisor, ResultFromSiblingBranch> {
+pub trait TrialDivisionTreeBranch1<DepthCount, Divisor, WitnessFromSiblingBranch> {
     type Output: Bit;
+    type Witness;
 }
 
-// If the sibling branch discovered that Self is composite,
-// short-circuit with that result.
-impl<N,C,D> TrialDivisionTreeBranch1<C,D,False> for N {
+// If the sibling branch found a divisor of Self, short-circuit with
+// that divisor as the witness.
+impl<N,C,D,Uw,Bw> TrialDivisionTreeBranch1<C,D,UInt<Uw,Bw>> for N {
     type Output = False;
+    type Witness = UInt<Uw,Bw>;
 }
 
-// If the sibling branch didn't discover that Self is composite,
-// continue the search.
-impl<N,C,D> TrialDivisionTreeBranch1<C,D,True> for N
+// If the sibling branch didn't find a divisor of Self, continue the
+// search.
+impl<N,C,D> TrialDivisionTreeBranch1<C,D,UTerm> for N
     where N: TrialDivisionTreeBranch0<C,D> {
     type Output = <N as TrialDivisionTreeBranch0<C,D>>::Output;
+    type Witness = <N as TrialDivisionTreeBranch0<C,D>>::Witness;
 }
@@ -0,0 +1,109 @@
+// src/rader.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::{Add, Mul};
+
+use generic_array::{ArrayLength, GenericArray};
+use generic_array::sequence::GenericSequence;
+use typenum::marker_traits::Unsigned;
+
+use PrimitiveRoot;
+
+
+/// The discrete Fourier transform of the prime-length `values`, in
+/// place, by Rader's algorithm.
+///
+/// Entry k becomes the sum of `values[j]·ω^(jk)`, where
+/// `twiddle(i)` must return ω^i for i in `0..N`. For the usual forward
+/// transform of complex values, ω is e^(-2πi/`N`); for the inverse,
+/// it is e^(2πi/`N`), and the result is divided by `N`.
+///
+/// The nonzero indices modulo `N` are the powers of the
+/// `PrimitiveRoot` g. Ordering the inputs by g^q and the outputs by
+/// g^-p turns the transform into a cyclic convolution of length
+/// `N`-1, which this function computes directly, in O(`N`²) steps. Use
+/// `rader_dft_with` to compute the convolution with a faster
+/// transform of the composite length `N`-1.
+///
+/// The sums of the convolution start from `T::default()`, which must
+/// therefore be the additive identity. It is for `Fp`, `Montgomery`
+/// and the primitive numeric types.
+///
+/// ```
+/// # extern crate typenum;
+/// # extern crate typenum_prime;
+/// # use typenum::consts::{U5, U11};
+/// # use typenum_prime::generic_array::GenericArray;
+/// # use typenum_prime::generic_array::sequence::GenericSequence;
+/// # use typenum_prime::{rader_dft, Fp};
+/// # fn main() {
+/// // 3 has order 5 modulo 11
+/// let root = Fp::<U11>::new(3);
+/// let mut values = GenericArray::<Fp<U11>, U5>::generate(|i| Fp::new(i as u64));
+/// rader_dft(&mut values, |i| root.pow(i as u64));
+/// let naive = |k: u64| (0..5).fold(Fp::ZERO, |acc, j| acc + Fp::new(j) * root.pow(j * k));
+/// assert!((0..5).all(|k| values[k as usize] == naive(k)));
+/// # }
+/// ```
+pub fn rader_dft<T,N,W>(values: &mut GenericArray<T,N>, twiddle: W)
+    where T: Copy + Default + Add<Output=T> + Mul<Output=T>,
+          N: PrimitiveRoot + ArrayLength,
+          W: FnMut(usize) -> T {
+    rader_dft_with(values, twiddle, |signal, kernel, out| {
+        let m = signal.len();
+        for (p, o) in out.iter_mut().enumerate() {
+            *o = (0..m).fold(T::default(), |acc, q| acc + signal[q] * kernel[(m + p - q) % m]);
+        }
+    });
+}
+
+/// The discrete Fourier transform of the prime-length `values`, in
+/// place, by Rader's algorithm with a caller-supplied convolution.
+///
+/// This is `rader_dft`, except that the cyclic convolution of length
+/// `N`-1 is left to `convolve(signal, kernel, out)`, which must set
+/// `out[p]` to the sum of `signal[q]·kernel[(p-q) mod (N-1)]`. The
+/// kernel depends only on `N` and the twiddle factors, so a fast
+/// convolution may cache its transform.
+///
+/// Unlike `rader_dft`, this function never adds to `T::default()`. It
+/// only fills the scratch buffers with it, so it need not be zero,
+/// but `out` starts out filled with it and `convolve` must overwrite
+/// every element.
+pub fn rader_dft_with<T,N,W,C>(values: &mut GenericArray<T,N>, mut twiddle: W, convolve: C)
+    where T: Copy + Default + Add<Output=T>,
+          N: PrimitiveRoot + ArrayLength,
+          W: FnMut(usize) -> T,
+          C: FnOnce(&[T], &[T], &mut [T]) {
+    let n = N::USIZE;
+    let m = n - 1;
+    let g = <N as PrimitiveRoot>::Output::USIZE;
+    // powers[q] == g^q mod N, so g^-p == powers[(m-p) % m]
+    let mut powers = GenericArray::<usize,N>::generate(|_| 1);
+    for q in 1..m {
+        powers[q] = powers[q - 1] * g % n;
+    }
+    let inverse_power = |p: usize| powers[(m - p) % m];
+
+    let mut signal = GenericArray::<T,N>::generate(|_| T::default());
+    let mut kernel = GenericArray::<T,N>::generate(|_| T::default());
+    let mut out = GenericArray::<T,N>::generate(|_| T::default());
+    for q in 0..m {
+        signal[q] = values[powers[q]];
+        kernel[q] = twiddle(inverse_power(q));
+    }
+    convolve(&signal[..m], &kernel[..m], &mut out[..m]);
+
+    let first = values[0];
+    values[0] = values.iter().skip(1).fold(first, |acc, &v| acc + v);
+    for (p, &c) in out[..m].iter().enumerate() {
+        values[inverse_power(p)] = first + c;
+    }
+}
@@ -1046,3 +1046,2057 @@ fn test_composite() {
     assert_type_eq!(<U1023 as IsPrime>::Output, False);
     assert_type_eq!(<U1024 as IsPrime>::Output, False);
 }
+
+#[test]
+fn test_runtime() {
+    assert_eq!(is_prime_u64(0), <U0 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(0), <U0 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1), <U1 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1), <U1 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(2), <U2 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(2), <U2 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(3), <U3 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(3), <U3 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(4), <U4 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(4), <U4 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(5), <U5 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(5), <U5 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(6), <U6 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(6), <U6 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(7), <U7 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(7), <U7 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(8), <U8 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(8), <U8 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(9), <U9 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(9), <U9 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(10), <U10 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(10), <U10 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(11), <U11 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(11), <U11 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(12), <U12 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(12), <U12 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(13), <U13 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(13), <U13 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(14), <U14 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(14), <U14 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(15), <U15 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(15), <U15 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(16), <U16 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(16), <U16 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(17), <U17 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(17), <U17 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(18), <U18 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(18), <U18 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(19), <U19 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(19), <U19 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(20), <U20 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(20), <U20 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(21), <U21 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(21), <U21 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(22), <U22 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(22), <U22 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(23), <U23 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(23), <U23 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(24), <U24 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(24), <U24 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(25), <U25 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(25), <U25 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(26), <U26 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(26), <U26 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(27), <U27 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(27), <U27 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(28), <U28 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(28), <U28 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(29), <U29 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(29), <U29 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(30), <U30 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(30), <U30 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(31), <U31 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(31), <U31 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(32), <U32 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(32), <U32 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(33), <U33 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(33), <U33 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(34), <U34 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(34), <U34 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(35), <U35 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(35), <U35 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(36), <U36 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(36), <U36 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(37), <U37 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(37), <U37 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(38), <U38 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(38), <U38 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(39), <U39 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(39), <U39 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(40), <U40 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(40), <U40 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(41), <U41 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(41), <U41 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(42), <U42 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(42), <U42 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(43), <U43 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(43), <U43 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(44), <U44 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(44), <U44 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(45), <U45 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(45), <U45 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(46), <U46 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(46), <U46 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(47), <U47 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(47), <U47 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(48), <U48 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(48), <U48 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(49), <U49 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(49), <U49 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(50), <U50 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(50), <U50 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(51), <U51 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(51), <U51 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(52), <U52 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(52), <U52 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(53), <U53 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(53), <U53 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(54), <U54 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(54), <U54 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(55), <U55 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(55), <U55 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(56), <U56 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(56), <U56 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(57), <U57 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(57), <U57 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(58), <U58 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(58), <U58 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(59), <U59 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(59), <U59 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(60), <U60 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(60), <U60 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(61), <U61 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(61), <U61 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(62), <U62 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(62), <U62 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(63), <U63 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(63), <U63 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(64), <U64 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(64), <U64 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(65), <U65 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(65), <U65 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(66), <U66 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(66), <U66 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(67), <U67 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(67), <U67 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(68), <U68 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(68), <U68 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(69), <U69 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(69), <U69 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(70), <U70 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(70), <U70 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(71), <U71 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(71), <U71 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(72), <U72 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(72), <U72 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(73), <U73 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(73), <U73 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(74), <U74 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(74), <U74 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(75), <U75 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(75), <U75 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(76), <U76 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(76), <U76 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(77), <U77 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(77), <U77 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(78), <U78 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(78), <U78 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(79), <U79 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(79), <U79 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(80), <U80 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(80), <U80 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(81), <U81 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(81), <U81 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(82), <U82 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(82), <U82 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(83), <U83 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(83), <U83 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(84), <U84 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(84), <U84 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(85), <U85 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(85), <U85 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(86), <U86 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(86), <U86 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(87), <U87 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(87), <U87 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(88), <U88 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(88), <U88 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(89), <U89 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(89), <U89 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(90), <U90 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(90), <U90 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(91), <U91 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(91), <U91 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(92), <U92 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(92), <U92 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(93), <U93 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(93), <U93 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(94), <U94 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(94), <U94 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(95), <U95 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(95), <U95 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(96), <U96 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(96), <U96 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(97), <U97 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(97), <U97 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(98), <U98 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(98), <U98 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(99), <U99 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(99), <U99 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(100), <U100 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(100), <U100 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(101), <U101 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(101), <U101 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(102), <U102 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(102), <U102 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(103), <U103 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(103), <U103 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(104), <U104 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(104), <U104 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(105), <U105 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(105), <U105 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(106), <U106 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(106), <U106 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(107), <U107 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(107), <U107 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(108), <U108 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(108), <U108 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(109), <U109 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(109), <U109 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(110), <U110 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(110), <U110 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(111), <U111 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(111), <U111 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(112), <U112 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(112), <U112 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(113), <U113 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(113), <U113 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(114), <U114 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(114), <U114 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(115), <U115 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(115), <U115 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(116), <U116 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(116), <U116 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(117), <U117 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(117), <U117 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(118), <U118 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(118), <U118 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(119), <U119 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(119), <U119 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(120), <U120 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(120), <U120 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(121), <U121 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(121), <U121 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(122), <U122 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(122), <U122 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(123), <U123 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(123), <U123 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(124), <U124 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(124), <U124 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(125), <U125 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(125), <U125 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(126), <U126 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(126), <U126 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(127), <U127 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(127), <U127 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(128), <U128 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(128), <U128 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(129), <U129 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(129), <U129 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(130), <U130 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(130), <U130 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(131), <U131 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(131), <U131 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(132), <U132 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(132), <U132 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(133), <U133 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(133), <U133 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(134), <U134 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(134), <U134 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(135), <U135 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(135), <U135 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(136), <U136 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(136), <U136 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(137), <U137 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(137), <U137 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(138), <U138 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(138), <U138 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(139), <U139 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(139), <U139 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(140), <U140 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(140), <U140 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(141), <U141 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(141), <U141 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(142), <U142 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(142), <U142 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(143), <U143 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(143), <U143 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(144), <U144 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(144), <U144 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(145), <U145 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(145), <U145 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(146), <U146 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(146), <U146 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(147), <U147 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(147), <U147 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(148), <U148 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(148), <U148 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(149), <U149 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(149), <U149 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(150), <U150 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(150), <U150 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(151), <U151 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(151), <U151 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(152), <U152 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(152), <U152 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(153), <U153 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(153), <U153 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(154), <U154 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(154), <U154 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(155), <U155 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(155), <U155 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(156), <U156 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(156), <U156 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(157), <U157 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(157), <U157 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(158), <U158 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(158), <U158 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(159), <U159 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(159), <U159 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(160), <U160 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(160), <U160 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(161), <U161 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(161), <U161 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(162), <U162 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(162), <U162 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(163), <U163 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(163), <U163 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(164), <U164 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(164), <U164 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(165), <U165 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(165), <U165 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(166), <U166 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(166), <U166 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(167), <U167 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(167), <U167 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(168), <U168 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(168), <U168 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(169), <U169 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(169), <U169 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(170), <U170 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(170), <U170 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(171), <U171 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(171), <U171 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(172), <U172 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(172), <U172 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(173), <U173 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(173), <U173 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(174), <U174 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(174), <U174 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(175), <U175 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(175), <U175 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(176), <U176 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(176), <U176 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(177), <U177 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(177), <U177 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(178), <U178 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(178), <U178 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(179), <U179 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(179), <U179 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(180), <U180 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(180), <U180 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(181), <U181 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(181), <U181 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(182), <U182 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(182), <U182 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(183), <U183 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(183), <U183 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(184), <U184 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(184), <U184 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(185), <U185 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(185), <U185 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(186), <U186 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(186), <U186 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(187), <U187 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(187), <U187 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(188), <U188 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(188), <U188 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(189), <U189 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(189), <U189 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(190), <U190 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(190), <U190 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(191), <U191 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(191), <U191 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(192), <U192 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(192), <U192 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(193), <U193 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(193), <U193 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(194), <U194 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(194), <U194 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(195), <U195 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(195), <U195 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(196), <U196 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(196), <U196 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(197), <U197 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(197), <U197 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(198), <U198 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(198), <U198 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(199), <U199 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(199), <U199 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(200), <U200 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(200), <U200 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(201), <U201 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(201), <U201 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(202), <U202 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(202), <U202 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(203), <U203 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(203), <U203 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(204), <U204 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(204), <U204 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(205), <U205 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(205), <U205 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(206), <U206 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(206), <U206 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(207), <U207 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(207), <U207 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(208), <U208 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(208), <U208 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(209), <U209 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(209), <U209 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(210), <U210 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(210), <U210 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(211), <U211 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(211), <U211 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(212), <U212 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(212), <U212 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(213), <U213 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(213), <U213 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(214), <U214 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(214), <U214 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(215), <U215 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(215), <U215 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(216), <U216 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(216), <U216 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(217), <U217 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(217), <U217 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(218), <U218 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(218), <U218 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(219), <U219 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(219), <U219 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(220), <U220 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(220), <U220 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(221), <U221 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(221), <U221 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(222), <U222 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(222), <U222 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(223), <U223 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(223), <U223 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(224), <U224 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(224), <U224 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(225), <U225 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(225), <U225 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(226), <U226 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(226), <U226 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(227), <U227 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(227), <U227 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(228), <U228 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(228), <U228 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(229), <U229 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(229), <U229 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(230), <U230 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(230), <U230 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(231), <U231 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(231), <U231 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(232), <U232 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(232), <U232 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(233), <U233 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(233), <U233 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(234), <U234 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(234), <U234 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(235), <U235 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(235), <U235 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(236), <U236 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(236), <U236 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(237), <U237 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(237), <U237 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(238), <U238 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(238), <U238 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(239), <U239 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(239), <U239 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(240), <U240 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(240), <U240 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(241), <U241 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(241), <U241 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(242), <U242 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(242), <U242 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(243), <U243 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(243), <U243 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(244), <U244 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(244), <U244 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(245), <U245 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(245), <U245 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(246), <U246 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(246), <U246 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(247), <U247 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(247), <U247 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(248), <U248 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(248), <U248 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(249), <U249 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(249), <U249 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(250), <U250 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(250), <U250 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(251), <U251 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(251), <U251 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(252), <U252 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(252), <U252 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(253), <U253 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(253), <U253 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(254), <U254 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(254), <U254 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(255), <U255 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(255), <U255 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(256), <U256 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(256), <U256 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(257), <U257 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(257), <U257 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(258), <U258 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(258), <U258 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(259), <U259 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(259), <U259 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(260), <U260 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(260), <U260 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(261), <U261 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(261), <U261 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(262), <U262 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(262), <U262 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(263), <U263 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(263), <U263 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(264), <U264 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(264), <U264 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(265), <U265 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(265), <U265 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(266), <U266 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(266), <U266 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(267), <U267 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(267), <U267 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(268), <U268 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(268), <U268 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(269), <U269 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(269), <U269 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(270), <U270 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(270), <U270 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(271), <U271 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(271), <U271 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(272), <U272 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(272), <U272 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(273), <U273 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(273), <U273 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(274), <U274 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(274), <U274 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(275), <U275 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(275), <U275 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(276), <U276 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(276), <U276 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(277), <U277 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(277), <U277 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(278), <U278 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(278), <U278 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(279), <U279 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(279), <U279 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(280), <U280 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(280), <U280 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(281), <U281 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(281), <U281 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(282), <U282 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(282), <U282 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(283), <U283 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(283), <U283 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(284), <U284 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(284), <U284 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(285), <U285 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(285), <U285 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(286), <U286 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(286), <U286 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(287), <U287 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(287), <U287 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(288), <U288 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(288), <U288 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(289), <U289 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(289), <U289 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(290), <U290 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(290), <U290 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(291), <U291 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(291), <U291 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(292), <U292 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(292), <U292 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(293), <U293 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(293), <U293 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(294), <U294 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(294), <U294 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(295), <U295 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(295), <U295 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(296), <U296 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(296), <U296 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(297), <U297 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(297), <U297 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(298), <U298 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(298), <U298 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(299), <U299 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(299), <U299 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(300), <U300 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(300), <U300 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(301), <U301 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(301), <U301 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(302), <U302 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(302), <U302 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(303), <U303 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(303), <U303 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(304), <U304 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(304), <U304 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(305), <U305 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(305), <U305 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(306), <U306 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(306), <U306 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(307), <U307 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(307), <U307 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(308), <U308 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(308), <U308 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(309), <U309 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(309), <U309 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(310), <U310 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(310), <U310 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(311), <U311 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(311), <U311 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(312), <U312 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(312), <U312 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(313), <U313 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(313), <U313 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(314), <U314 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(314), <U314 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(315), <U315 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(315), <U315 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(316), <U316 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(316), <U316 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(317), <U317 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(317), <U317 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(318), <U318 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(318), <U318 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(319), <U319 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(319), <U319 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(320), <U320 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(320), <U320 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(321), <U321 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(321), <U321 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(322), <U322 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(322), <U322 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(323), <U323 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(323), <U323 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(324), <U324 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(324), <U324 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(325), <U325 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(325), <U325 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(326), <U326 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(326), <U326 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(327), <U327 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(327), <U327 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(328), <U328 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(328), <U328 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(329), <U329 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(329), <U329 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(330), <U330 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(330), <U330 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(331), <U331 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(331), <U331 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(332), <U332 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(332), <U332 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(333), <U333 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(333), <U333 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(334), <U334 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(334), <U334 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(335), <U335 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(335), <U335 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(336), <U336 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(336), <U336 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(337), <U337 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(337), <U337 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(338), <U338 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(338), <U338 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(339), <U339 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(339), <U339 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(340), <U340 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(340), <U340 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(341), <U341 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(341), <U341 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(342), <U342 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(342), <U342 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(343), <U343 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(343), <U343 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(344), <U344 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(344), <U344 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(345), <U345 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(345), <U345 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(346), <U346 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(346), <U346 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(347), <U347 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(347), <U347 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(348), <U348 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(348), <U348 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(349), <U349 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(349), <U349 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(350), <U350 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(350), <U350 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(351), <U351 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(351), <U351 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(352), <U352 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(352), <U352 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(353), <U353 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(353), <U353 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(354), <U354 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(354), <U354 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(355), <U355 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(355), <U355 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(356), <U356 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(356), <U356 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(357), <U357 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(357), <U357 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(358), <U358 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(358), <U358 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(359), <U359 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(359), <U359 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(360), <U360 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(360), <U360 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(361), <U361 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(361), <U361 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(362), <U362 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(362), <U362 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(363), <U363 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(363), <U363 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(364), <U364 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(364), <U364 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(365), <U365 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(365), <U365 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(366), <U366 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(366), <U366 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(367), <U367 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(367), <U367 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(368), <U368 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(368), <U368 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(369), <U369 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(369), <U369 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(370), <U370 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(370), <U370 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(371), <U371 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(371), <U371 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(372), <U372 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(372), <U372 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(373), <U373 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(373), <U373 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(374), <U374 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(374), <U374 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(375), <U375 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(375), <U375 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(376), <U376 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(376), <U376 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(377), <U377 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(377), <U377 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(378), <U378 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(378), <U378 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(379), <U379 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(379), <U379 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(380), <U380 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(380), <U380 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(381), <U381 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(381), <U381 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(382), <U382 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(382), <U382 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(383), <U383 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(383), <U383 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(384), <U384 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(384), <U384 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(385), <U385 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(385), <U385 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(386), <U386 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(386), <U386 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(387), <U387 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(387), <U387 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(388), <U388 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(388), <U388 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(389), <U389 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(389), <U389 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(390), <U390 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(390), <U390 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(391), <U391 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(391), <U391 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(392), <U392 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(392), <U392 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(393), <U393 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(393), <U393 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(394), <U394 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(394), <U394 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(395), <U395 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(395), <U395 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(396), <U396 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(396), <U396 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(397), <U397 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(397), <U397 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(398), <U398 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(398), <U398 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(399), <U399 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(399), <U399 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(400), <U400 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(400), <U400 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(401), <U401 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(401), <U401 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(402), <U402 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(402), <U402 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(403), <U403 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(403), <U403 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(404), <U404 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(404), <U404 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(405), <U405 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(405), <U405 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(406), <U406 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(406), <U406 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(407), <U407 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(407), <U407 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(408), <U408 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(408), <U408 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(409), <U409 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(409), <U409 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(410), <U410 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(410), <U410 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(411), <U411 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(411), <U411 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(412), <U412 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(412), <U412 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(413), <U413 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(413), <U413 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(414), <U414 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(414), <U414 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(415), <U415 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(415), <U415 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(416), <U416 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(416), <U416 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(417), <U417 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(417), <U417 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(418), <U418 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(418), <U418 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(419), <U419 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(419), <U419 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(420), <U420 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(420), <U420 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(421), <U421 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(421), <U421 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(422), <U422 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(422), <U422 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(423), <U423 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(423), <U423 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(424), <U424 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(424), <U424 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(425), <U425 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(425), <U425 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(426), <U426 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(426), <U426 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(427), <U427 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(427), <U427 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(428), <U428 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(428), <U428 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(429), <U429 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(429), <U429 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(430), <U430 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(430), <U430 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(431), <U431 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(431), <U431 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(432), <U432 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(432), <U432 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(433), <U433 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(433), <U433 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(434), <U434 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(434), <U434 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(435), <U435 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(435), <U435 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(436), <U436 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(436), <U436 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(437), <U437 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(437), <U437 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(438), <U438 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(438), <U438 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(439), <U439 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(439), <U439 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(440), <U440 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(440), <U440 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(441), <U441 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(441), <U441 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(442), <U442 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(442), <U442 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(443), <U443 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(443), <U443 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(444), <U444 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(444), <U444 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(445), <U445 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(445), <U445 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(446), <U446 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(446), <U446 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(447), <U447 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(447), <U447 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(448), <U448 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(448), <U448 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(449), <U449 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(449), <U449 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(450), <U450 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(450), <U450 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(451), <U451 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(451), <U451 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(452), <U452 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(452), <U452 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(453), <U453 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(453), <U453 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(454), <U454 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(454), <U454 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(455), <U455 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(455), <U455 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(456), <U456 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(456), <U456 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(457), <U457 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(457), <U457 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(458), <U458 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(458), <U458 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(459), <U459 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(459), <U459 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(460), <U460 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(460), <U460 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(461), <U461 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(461), <U461 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(462), <U462 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(462), <U462 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(463), <U463 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(463), <U463 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(464), <U464 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(464), <U464 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(465), <U465 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(465), <U465 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(466), <U466 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(466), <U466 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(467), <U467 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(467), <U467 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(468), <U468 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(468), <U468 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(469), <U469 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(469), <U469 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(470), <U470 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(470), <U470 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(471), <U471 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(471), <U471 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(472), <U472 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(472), <U472 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(473), <U473 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(473), <U473 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(474), <U474 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(474), <U474 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(475), <U475 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(475), <U475 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(476), <U476 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(476), <U476 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(477), <U477 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(477), <U477 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(478), <U478 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(478), <U478 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(479), <U479 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(479), <U479 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(480), <U480 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(480), <U480 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(481), <U481 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(481), <U481 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(482), <U482 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(482), <U482 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(483), <U483 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(483), <U483 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(484), <U484 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(484), <U484 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(485), <U485 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(485), <U485 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(486), <U486 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(486), <U486 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(487), <U487 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(487), <U487 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(488), <U488 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(488), <U488 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(489), <U489 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(489), <U489 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(490), <U490 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(490), <U490 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(491), <U491 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(491), <U491 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(492), <U492 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(492), <U492 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(493), <U493 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(493), <U493 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(494), <U494 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(494), <U494 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(495), <U495 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(495), <U495 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(496), <U496 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(496), <U496 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(497), <U497 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(497), <U497 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(498), <U498 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(498), <U498 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(499), <U499 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(499), <U499 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(500), <U500 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(500), <U500 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(501), <U501 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(501), <U501 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(502), <U502 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(502), <U502 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(503), <U503 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(503), <U503 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(504), <U504 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(504), <U504 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(505), <U505 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(505), <U505 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(506), <U506 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(506), <U506 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(507), <U507 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(507), <U507 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(508), <U508 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(508), <U508 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(509), <U509 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(509), <U509 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(510), <U510 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(510), <U510 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(511), <U511 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(511), <U511 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(512), <U512 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(512), <U512 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(513), <U513 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(513), <U513 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(514), <U514 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(514), <U514 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(515), <U515 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(515), <U515 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(516), <U516 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(516), <U516 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(517), <U517 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(517), <U517 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(518), <U518 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(518), <U518 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(519), <U519 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(519), <U519 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(520), <U520 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(520), <U520 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(521), <U521 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(521), <U521 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(522), <U522 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(522), <U522 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(523), <U523 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(523), <U523 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(524), <U524 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(524), <U524 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(525), <U525 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(525), <U525 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(526), <U526 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(526), <U526 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(527), <U527 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(527), <U527 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(528), <U528 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(528), <U528 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(529), <U529 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(529), <U529 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(530), <U530 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(530), <U530 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(531), <U531 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(531), <U531 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(532), <U532 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(532), <U532 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(533), <U533 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(533), <U533 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(534), <U534 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(534), <U534 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(535), <U535 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(535), <U535 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(536), <U536 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(536), <U536 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(537), <U537 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(537), <U537 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(538), <U538 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(538), <U538 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(539), <U539 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(539), <U539 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(540), <U540 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(540), <U540 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(541), <U541 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(541), <U541 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(542), <U542 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(542), <U542 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(543), <U543 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(543), <U543 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(544), <U544 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(544), <U544 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(545), <U545 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(545), <U545 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(546), <U546 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(546), <U546 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(547), <U547 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(547), <U547 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(548), <U548 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(548), <U548 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(549), <U549 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(549), <U549 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(550), <U550 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(550), <U550 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(551), <U551 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(551), <U551 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(552), <U552 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(552), <U552 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(553), <U553 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(553), <U553 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(554), <U554 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(554), <U554 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(555), <U555 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(555), <U555 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(556), <U556 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(556), <U556 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(557), <U557 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(557), <U557 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(558), <U558 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(558), <U558 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(559), <U559 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(559), <U559 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(560), <U560 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(560), <U560 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(561), <U561 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(561), <U561 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(562), <U562 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(562), <U562 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(563), <U563 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(563), <U563 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(564), <U564 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(564), <U564 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(565), <U565 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(565), <U565 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(566), <U566 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(566), <U566 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(567), <U567 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(567), <U567 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(568), <U568 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(568), <U568 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(569), <U569 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(569), <U569 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(570), <U570 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(570), <U570 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(571), <U571 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(571), <U571 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(572), <U572 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(572), <U572 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(573), <U573 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(573), <U573 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(574), <U574 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(574), <U574 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(575), <U575 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(575), <U575 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(576), <U576 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(576), <U576 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(577), <U577 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(577), <U577 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(578), <U578 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(578), <U578 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(579), <U579 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(579), <U579 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(580), <U580 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(580), <U580 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(581), <U581 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(581), <U581 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(582), <U582 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(582), <U582 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(583), <U583 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(583), <U583 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(584), <U584 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(584), <U584 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(585), <U585 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(585), <U585 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(586), <U586 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(586), <U586 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(587), <U587 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(587), <U587 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(588), <U588 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(588), <U588 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(589), <U589 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(589), <U589 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(590), <U590 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(590), <U590 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(591), <U591 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(591), <U591 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(592), <U592 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(592), <U592 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(593), <U593 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(593), <U593 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(594), <U594 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(594), <U594 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(595), <U595 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(595), <U595 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(596), <U596 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(596), <U596 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(597), <U597 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(597), <U597 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(598), <U598 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(598), <U598 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(599), <U599 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(599), <U599 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(600), <U600 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(600), <U600 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(601), <U601 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(601), <U601 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(602), <U602 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(602), <U602 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(603), <U603 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(603), <U603 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(604), <U604 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(604), <U604 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(605), <U605 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(605), <U605 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(606), <U606 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(606), <U606 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(607), <U607 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(607), <U607 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(608), <U608 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(608), <U608 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(609), <U609 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(609), <U609 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(610), <U610 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(610), <U610 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(611), <U611 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(611), <U611 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(612), <U612 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(612), <U612 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(613), <U613 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(613), <U613 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(614), <U614 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(614), <U614 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(615), <U615 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(615), <U615 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(616), <U616 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(616), <U616 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(617), <U617 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(617), <U617 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(618), <U618 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(618), <U618 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(619), <U619 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(619), <U619 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(620), <U620 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(620), <U620 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(621), <U621 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(621), <U621 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(622), <U622 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(622), <U622 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(623), <U623 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(623), <U623 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(624), <U624 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(624), <U624 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(625), <U625 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(625), <U625 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(626), <U626 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(626), <U626 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(627), <U627 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(627), <U627 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(628), <U628 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(628), <U628 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(629), <U629 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(629), <U629 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(630), <U630 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(630), <U630 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(631), <U631 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(631), <U631 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(632), <U632 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(632), <U632 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(633), <U633 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(633), <U633 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(634), <U634 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(634), <U634 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(635), <U635 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(635), <U635 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(636), <U636 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(636), <U636 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(637), <U637 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(637), <U637 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(638), <U638 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(638), <U638 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(639), <U639 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(639), <U639 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(640), <U640 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(640), <U640 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(641), <U641 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(641), <U641 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(642), <U642 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(642), <U642 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(643), <U643 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(643), <U643 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(644), <U644 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(644), <U644 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(645), <U645 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(645), <U645 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(646), <U646 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(646), <U646 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(647), <U647 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(647), <U647 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(648), <U648 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(648), <U648 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(649), <U649 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(649), <U649 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(650), <U650 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(650), <U650 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(651), <U651 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(651), <U651 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(652), <U652 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(652), <U652 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(653), <U653 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(653), <U653 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(654), <U654 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(654), <U654 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(655), <U655 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(655), <U655 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(656), <U656 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(656), <U656 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(657), <U657 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(657), <U657 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(658), <U658 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(658), <U658 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(659), <U659 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(659), <U659 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(660), <U660 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(660), <U660 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(661), <U661 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(661), <U661 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(662), <U662 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(662), <U662 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(663), <U663 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(663), <U663 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(664), <U664 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(664), <U664 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(665), <U665 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(665), <U665 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(666), <U666 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(666), <U666 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(667), <U667 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(667), <U667 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(668), <U668 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(668), <U668 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(669), <U669 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(669), <U669 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(670), <U670 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(670), <U670 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(671), <U671 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(671), <U671 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(672), <U672 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(672), <U672 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(673), <U673 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(673), <U673 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(674), <U674 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(674), <U674 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(675), <U675 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(675), <U675 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(676), <U676 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(676), <U676 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(677), <U677 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(677), <U677 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(678), <U678 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(678), <U678 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(679), <U679 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(679), <U679 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(680), <U680 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(680), <U680 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(681), <U681 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(681), <U681 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(682), <U682 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(682), <U682 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(683), <U683 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(683), <U683 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(684), <U684 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(684), <U684 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(685), <U685 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(685), <U685 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(686), <U686 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(686), <U686 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(687), <U687 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(687), <U687 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(688), <U688 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(688), <U688 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(689), <U689 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(689), <U689 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(690), <U690 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(690), <U690 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(691), <U691 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(691), <U691 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(692), <U692 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(692), <U692 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(693), <U693 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(693), <U693 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(694), <U694 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(694), <U694 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(695), <U695 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(695), <U695 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(696), <U696 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(696), <U696 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(697), <U697 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(697), <U697 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(698), <U698 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(698), <U698 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(699), <U699 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(699), <U699 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(700), <U700 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(700), <U700 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(701), <U701 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(701), <U701 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(702), <U702 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(702), <U702 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(703), <U703 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(703), <U703 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(704), <U704 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(704), <U704 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(705), <U705 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(705), <U705 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(706), <U706 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(706), <U706 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(707), <U707 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(707), <U707 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(708), <U708 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(708), <U708 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(709), <U709 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(709), <U709 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(710), <U710 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(710), <U710 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(711), <U711 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(711), <U711 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(712), <U712 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(712), <U712 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(713), <U713 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(713), <U713 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(714), <U714 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(714), <U714 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(715), <U715 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(715), <U715 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(716), <U716 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(716), <U716 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(717), <U717 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(717), <U717 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(718), <U718 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(718), <U718 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(719), <U719 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(719), <U719 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(720), <U720 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(720), <U720 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(721), <U721 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(721), <U721 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(722), <U722 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(722), <U722 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(723), <U723 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(723), <U723 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(724), <U724 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(724), <U724 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(725), <U725 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(725), <U725 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(726), <U726 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(726), <U726 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(727), <U727 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(727), <U727 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(728), <U728 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(728), <U728 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(729), <U729 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(729), <U729 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(730), <U730 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(730), <U730 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(731), <U731 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(731), <U731 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(732), <U732 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(732), <U732 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(733), <U733 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(733), <U733 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(734), <U734 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(734), <U734 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(735), <U735 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(735), <U735 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(736), <U736 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(736), <U736 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(737), <U737 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(737), <U737 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(738), <U738 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(738), <U738 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(739), <U739 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(739), <U739 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(740), <U740 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(740), <U740 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(741), <U741 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(741), <U741 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(742), <U742 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(742), <U742 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(743), <U743 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(743), <U743 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(744), <U744 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(744), <U744 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(745), <U745 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(745), <U745 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(746), <U746 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(746), <U746 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(747), <U747 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(747), <U747 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(748), <U748 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(748), <U748 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(749), <U749 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(749), <U749 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(750), <U750 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(750), <U750 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(751), <U751 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(751), <U751 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(752), <U752 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(752), <U752 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(753), <U753 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(753), <U753 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(754), <U754 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(754), <U754 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(755), <U755 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(755), <U755 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(756), <U756 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(756), <U756 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(757), <U757 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(757), <U757 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(758), <U758 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(758), <U758 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(759), <U759 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(759), <U759 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(760), <U760 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(760), <U760 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(761), <U761 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(761), <U761 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(762), <U762 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(762), <U762 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(763), <U763 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(763), <U763 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(764), <U764 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(764), <U764 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(765), <U765 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(765), <U765 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(766), <U766 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(766), <U766 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(767), <U767 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(767), <U767 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(768), <U768 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(768), <U768 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(769), <U769 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(769), <U769 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(770), <U770 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(770), <U770 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(771), <U771 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(771), <U771 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(772), <U772 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(772), <U772 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(773), <U773 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(773), <U773 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(774), <U774 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(774), <U774 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(775), <U775 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(775), <U775 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(776), <U776 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(776), <U776 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(777), <U777 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(777), <U777 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(778), <U778 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(778), <U778 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(779), <U779 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(779), <U779 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(780), <U780 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(780), <U780 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(781), <U781 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(781), <U781 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(782), <U782 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(782), <U782 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(783), <U783 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(783), <U783 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(784), <U784 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(784), <U784 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(785), <U785 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(785), <U785 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(786), <U786 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(786), <U786 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(787), <U787 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(787), <U787 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(788), <U788 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(788), <U788 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(789), <U789 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(789), <U789 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(790), <U790 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(790), <U790 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(791), <U791 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(791), <U791 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(792), <U792 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(792), <U792 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(793), <U793 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(793), <U793 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(794), <U794 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(794), <U794 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(795), <U795 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(795), <U795 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(796), <U796 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(796), <U796 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(797), <U797 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(797), <U797 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(798), <U798 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(798), <U798 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(799), <U799 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(799), <U799 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(800), <U800 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(800), <U800 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(801), <U801 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(801), <U801 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(802), <U802 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(802), <U802 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(803), <U803 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(803), <U803 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(804), <U804 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(804), <U804 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(805), <U805 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(805), <U805 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(806), <U806 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(806), <U806 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(807), <U807 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(807), <U807 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(808), <U808 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(808), <U808 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(809), <U809 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(809), <U809 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(810), <U810 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(810), <U810 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(811), <U811 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(811), <U811 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(812), <U812 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(812), <U812 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(813), <U813 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(813), <U813 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(814), <U814 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(814), <U814 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(815), <U815 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(815), <U815 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(816), <U816 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(816), <U816 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(817), <U817 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(817), <U817 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(818), <U818 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(818), <U818 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(819), <U819 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(819), <U819 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(820), <U820 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(820), <U820 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(821), <U821 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(821), <U821 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(822), <U822 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(822), <U822 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(823), <U823 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(823), <U823 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(824), <U824 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(824), <U824 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(825), <U825 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(825), <U825 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(826), <U826 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(826), <U826 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(827), <U827 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(827), <U827 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(828), <U828 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(828), <U828 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(829), <U829 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(829), <U829 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(830), <U830 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(830), <U830 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(831), <U831 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(831), <U831 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(832), <U832 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(832), <U832 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(833), <U833 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(833), <U833 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(834), <U834 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(834), <U834 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(835), <U835 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(835), <U835 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(836), <U836 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(836), <U836 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(837), <U837 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(837), <U837 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(838), <U838 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(838), <U838 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(839), <U839 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(839), <U839 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(840), <U840 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(840), <U840 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(841), <U841 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(841), <U841 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(842), <U842 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(842), <U842 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(843), <U843 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(843), <U843 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(844), <U844 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(844), <U844 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(845), <U845 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(845), <U845 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(846), <U846 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(846), <U846 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(847), <U847 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(847), <U847 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(848), <U848 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(848), <U848 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(849), <U849 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(849), <U849 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(850), <U850 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(850), <U850 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(851), <U851 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(851), <U851 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(852), <U852 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(852), <U852 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(853), <U853 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(853), <U853 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(854), <U854 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(854), <U854 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(855), <U855 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(855), <U855 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(856), <U856 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(856), <U856 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(857), <U857 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(857), <U857 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(858), <U858 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(858), <U858 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(859), <U859 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(859), <U859 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(860), <U860 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(860), <U860 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(861), <U861 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(861), <U861 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(862), <U862 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(862), <U862 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(863), <U863 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(863), <U863 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(864), <U864 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(864), <U864 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(865), <U865 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(865), <U865 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(866), <U866 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(866), <U866 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(867), <U867 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(867), <U867 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(868), <U868 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(868), <U868 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(869), <U869 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(869), <U869 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(870), <U870 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(870), <U870 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(871), <U871 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(871), <U871 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(872), <U872 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(872), <U872 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(873), <U873 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(873), <U873 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(874), <U874 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(874), <U874 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(875), <U875 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(875), <U875 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(876), <U876 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(876), <U876 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(877), <U877 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(877), <U877 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(878), <U878 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(878), <U878 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(879), <U879 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(879), <U879 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(880), <U880 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(880), <U880 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(881), <U881 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(881), <U881 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(882), <U882 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(882), <U882 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(883), <U883 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(883), <U883 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(884), <U884 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(884), <U884 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(885), <U885 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(885), <U885 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(886), <U886 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(886), <U886 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(887), <U887 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(887), <U887 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(888), <U888 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(888), <U888 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(889), <U889 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(889), <U889 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(890), <U890 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(890), <U890 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(891), <U891 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(891), <U891 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(892), <U892 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(892), <U892 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(893), <U893 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(893), <U893 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(894), <U894 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(894), <U894 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(895), <U895 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(895), <U895 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(896), <U896 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(896), <U896 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(897), <U897 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(897), <U897 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(898), <U898 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(898), <U898 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(899), <U899 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(899), <U899 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(900), <U900 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(900), <U900 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(901), <U901 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(901), <U901 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(902), <U902 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(902), <U902 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(903), <U903 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(903), <U903 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(904), <U904 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(904), <U904 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(905), <U905 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(905), <U905 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(906), <U906 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(906), <U906 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(907), <U907 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(907), <U907 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(908), <U908 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(908), <U908 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(909), <U909 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(909), <U909 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(910), <U910 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(910), <U910 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(911), <U911 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(911), <U911 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(912), <U912 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(912), <U912 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(913), <U913 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(913), <U913 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(914), <U914 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(914), <U914 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(915), <U915 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(915), <U915 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(916), <U916 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(916), <U916 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(917), <U917 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(917), <U917 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(918), <U918 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(918), <U918 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(919), <U919 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(919), <U919 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(920), <U920 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(920), <U920 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(921), <U921 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(921), <U921 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(922), <U922 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(922), <U922 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(923), <U923 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(923), <U923 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(924), <U924 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(924), <U924 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(925), <U925 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(925), <U925 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(926), <U926 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(926), <U926 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(927), <U927 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(927), <U927 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(928), <U928 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(928), <U928 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(929), <U929 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(929), <U929 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(930), <U930 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(930), <U930 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(931), <U931 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(931), <U931 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(932), <U932 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(932), <U932 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(933), <U933 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(933), <U933 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(934), <U934 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(934), <U934 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(935), <U935 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(935), <U935 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(936), <U936 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(936), <U936 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(937), <U937 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(937), <U937 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(938), <U938 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(938), <U938 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(939), <U939 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(939), <U939 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(940), <U940 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(940), <U940 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(941), <U941 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(941), <U941 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(942), <U942 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(942), <U942 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(943), <U943 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(943), <U943 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(944), <U944 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(944), <U944 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(945), <U945 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(945), <U945 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(946), <U946 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(946), <U946 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(947), <U947 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(947), <U947 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(948), <U948 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(948), <U948 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(949), <U949 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(949), <U949 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(950), <U950 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(950), <U950 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(951), <U951 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(951), <U951 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(952), <U952 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(952), <U952 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(953), <U953 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(953), <U953 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(954), <U954 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(954), <U954 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(955), <U955 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(955), <U955 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(956), <U956 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(956), <U956 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(957), <U957 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(957), <U957 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(958), <U958 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(958), <U958 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(959), <U959 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(959), <U959 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(960), <U960 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(960), <U960 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(961), <U961 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(961), <U961 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(962), <U962 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(962), <U962 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(963), <U963 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(963), <U963 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(964), <U964 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(964), <U964 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(965), <U965 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(965), <U965 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(966), <U966 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(966), <U966 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(967), <U967 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(967), <U967 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(968), <U968 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(968), <U968 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(969), <U969 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(969), <U969 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(970), <U970 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(970), <U970 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(971), <U971 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(971), <U971 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(972), <U972 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(972), <U972 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(973), <U973 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(973), <U973 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(974), <U974 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(974), <U974 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(975), <U975 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(975), <U975 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(976), <U976 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(976), <U976 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(977), <U977 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(977), <U977 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(978), <U978 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(978), <U978 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(979), <U979 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(979), <U979 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(980), <U980 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(980), <U980 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(981), <U981 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(981), <U981 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(982), <U982 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(982), <U982 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(983), <U983 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(983), <U983 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(984), <U984 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(984), <U984 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(985), <U985 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(985), <U985 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(986), <U986 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(986), <U986 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(987), <U987 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(987), <U987 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(988), <U988 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(988), <U988 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(989), <U989 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(989), <U989 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(990), <U990 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(990), <U990 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(991), <U991 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(991), <U991 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(992), <U992 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(992), <U992 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(993), <U993 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(993), <U993 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(994), <U994 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(994), <U994 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(995), <U995 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(995), <U995 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(996), <U996 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(996), <U996 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(997), <U997 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(997), <U997 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(998), <U998 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(998), <U998 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(999), <U999 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(999), <U999 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1000), <U1000 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1000), <U1000 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1001), <U1001 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1001), <U1001 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1002), <U1002 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1002), <U1002 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1003), <U1003 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1003), <U1003 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1004), <U1004 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1004), <U1004 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1005), <U1005 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1005), <U1005 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1006), <U1006 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1006), <U1006 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1007), <U1007 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1007), <U1007 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1008), <U1008 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1008), <U1008 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1009), <U1009 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1009), <U1009 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1010), <U1010 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1010), <U1010 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1011), <U1011 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1011), <U1011 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1012), <U1012 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1012), <U1012 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1013), <U1013 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1013), <U1013 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1014), <U1014 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1014), <U1014 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1015), <U1015 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1015), <U1015 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1016), <U1016 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1016), <U1016 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1017), <U1017 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1017), <U1017 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1018), <U1018 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1018), <U1018 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1019), <U1019 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1019), <U1019 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1020), <U1020 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1020), <U1020 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1021), <U1021 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1021), <U1021 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1022), <U1022 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1022), <U1022 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1023), <U1023 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1023), <U1023 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u64(1024), <U1024 as IsPrime>::Output::to_bool());
+    assert_eq!(is_prime_u128(1024), <U1024 as IsPrime>::Output::to_bool());
+}
@@ -0,0 +1,33 @@
+// test/const_generics.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "const-generics")]
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::Const;
+
+use typenum_prime::{ConstPrime, is_prime_u64};
+
+
+fn checked_prime<const N: usize>() -> usize where Const<N>: ConstPrime {
+    N
+}
+
+
+#[test]
+fn test_const_prime() {
+    assert!(is_prime_u64(checked_prime::<2>() as u64));
+    assert!(is_prime_u64(checked_prime::<3>() as u64));
+    assert!(is_prime_u64(checked_prime::<97>() as u64));
+    assert!(is_prime_u64(checked_prime::<509>() as u64));
+    assert!(is_prime_u64(checked_prime::<1021>() as u64));
+}
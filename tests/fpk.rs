@@ -0,0 +1,112 @@
+// test/fpk.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "generic-array")]
+
+#[macro_use] extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::*;
+use typenum::marker_traits::Unsigned;
+
+use typenum_prime::{ExtensionModulus, Fp, Fpk, Prime};
+
+
+// GF(49) from x^2+1 over GF(7)
+type F49 = Fpk<U7, tarr![U1, U0, U1]>;
+
+// GF(81) from x^4+x+2 over GF(3)
+type F81 = Fpk<U3, tarr![U2, U1, U0, U0, U1]>;
+
+
+// Every element of the field, by the digits of 0 through P^k-1.
+fn elements<P,Poly>() -> Vec<Fpk<P,Poly>>
+    where P: Prime, Poly: ExtensionModulus<P> {
+    let (p, k) = (P::U64, Poly::Degree::U32);
+    (0..p.pow(k))
+        .map(|n| {
+            let digits: Vec<u64> = (0..k).map(|i| n / p.pow(i) % p).collect();
+            Fpk::from_coeffs(&digits)
+        })
+        .collect()
+}
+
+
+fn check_field_axioms<P,Poly>()
+    where P: Prime, Poly: ExtensionModulus<P>, Fpk<P,Poly>: Copy {
+    let elements = elements::<P,Poly>();
+    let order = elements.len() as u64;
+    for &a in &elements {
+        assert_eq!(a + Fpk::zero(), a);
+        assert_eq!(a * Fpk::one(), a);
+        assert_eq!(a + -a, Fpk::zero());
+        assert_eq!(a.pow(order), a);
+        if !a.is_zero() {
+            assert_eq!(a * a.inv().unwrap(), Fpk::one());
+        }
+    }
+    for &a in elements.iter().step_by(7) {
+        for &b in elements.iter().step_by(5) {
+            assert_eq!(a * b, b * a);
+            assert_eq!(a - b + b, a);
+            if !b.is_zero() {
+                assert_eq!(a / b * b, a);
+            }
+        }
+    }
+    assert_eq!(Fpk::<P,Poly>::zero().inv(), None);
+}
+
+
+#[test]
+fn test_field_axioms() {
+    check_field_axioms::<U7, tarr![U1, U0, U1]>();
+    check_field_axioms::<U3, tarr![U2, U1, U0, U0, U1]>();
+    // a polynomial that is not monic and has unreduced coefficients
+    check_field_axioms::<U5, tarr![U8, U6, U9]>();
+}
+
+
+#[test]
+fn test_frobenius() {
+    let a = F81::from_coeffs(&[2, 0, 1, 1]);
+    let b = F81::from_coeffs(&[1, 1]);
+    assert_eq!((a + b).frobenius(), a.frobenius() + b.frobenius());
+    assert_eq!((a * b).frobenius(), a.frobenius() * b.frobenius());
+    // x generates GF(81), so its orbit has all four conjugates
+    let mut c = F81::x();
+    for _ in 1..4 {
+        c = c.frobenius();
+        assert_ne!(c, F81::x());
+    }
+    assert_eq!(c.frobenius(), F81::x());
+    // the Frobenius automorphism fixes the base field
+    let two = F81::from(Fp::new(2));
+    assert_eq!(two.frobenius(), two);
+}
+
+
+#[test]
+fn test_from_coeffs_reduces() {
+    // x^2 == -1 == 6
+    assert_eq!(F49::from_coeffs(&[0, 0, 1]), F49::from_coeffs(&[6]));
+    assert_eq!(F49::from_coeffs(&[9, 8, 1]), F49::from_coeffs(&[1, 1]));
+    assert_eq!(F49::from_coeffs(&[]), F49::zero());
+    assert_eq!(F49::x().coeffs(), &[Fp::new(0), Fp::new(1)]);
+}
+
+
+#[test]
+fn test_display() {
+    assert_eq!(F49::zero().to_string(), "0");
+    assert_eq!(F49::x().to_string(), "x");
+    assert_eq!(F81::from_coeffs(&[1, 0, 2, 1]).to_string(), "x^3 + 2x^2 + 1");
+    assert_eq!(format!("{:?}", F49::from_coeffs(&[3, 2])), "2x + 3 (mod 7)");
+}
@@ -0,0 +1,143 @@
+// test/hash_table.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "generic-array")]
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::*;
+
+use typenum_prime::StaticHashTable;
+
+
+#[test]
+fn test_insert_get_replace() {
+    let mut table = StaticHashTable::<u32, u32, U7>::new();
+    assert!(table.is_empty());
+    assert_eq!(table.insert(1, 10), Ok(None));
+    assert_eq!(table.insert(2, 20), Ok(None));
+    assert_eq!(table.insert(1, 11), Ok(Some(10)));
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get(&1), Some(&11));
+    assert_eq!(table.get(&3), None);
+    *table.get_mut(&2).unwrap() += 1;
+    assert_eq!(table.get(&2), Some(&21));
+}
+
+
+#[test]
+fn test_fill_to_capacity() {
+    let mut table = StaticHashTable::<u64, u64, U13>::new();
+    for k in 0..13 {
+        assert_eq!(table.insert(k * 1_000, k), Ok(None));
+    }
+    assert_eq!(table.len(), table.capacity());
+    assert_eq!(table.insert(13_000, 13), Err((13_000, 13)));
+    for k in 0..13 {
+        assert_eq!(table.get(&(k * 1_000)), Some(&k));
+    }
+    // replacing an existing key still works when the table is full
+    assert_eq!(table.insert(0, 100), Ok(Some(0)));
+}
+
+
+#[test]
+fn test_remove_and_reinsert() {
+    let mut table = StaticHashTable::<u64, u64, U11>::new();
+    for k in 0..11 {
+        table.insert(k, k).unwrap();
+    }
+    for k in (0..11).filter(|k| k % 2 == 0) {
+        assert_eq!(table.remove(&k), Some(k));
+    }
+    assert_eq!(table.remove(&0), None);
+    assert_eq!(table.len(), 5);
+    // keys probed past removed buckets are still found
+    for k in (0..11).filter(|k| k % 2 == 1) {
+        assert!(table.contains_key(&k));
+    }
+    // and replaced rather than inserted again
+    for k in (0..11).filter(|k| k % 2 == 1) {
+        assert_eq!(table.insert(k, k + 1), Ok(Some(k)));
+    }
+    assert_eq!(table.len(), 5);
+    for k in 100..106 {
+        assert_eq!(table.insert(k, k), Ok(None));
+    }
+    assert_eq!(table.len(), 11);
+}
+
+
+#[test]
+fn test_iter_and_clear() {
+    let mut table = StaticHashTable::<&str, usize, U5>::new();
+    table.insert("a", 1).unwrap();
+    table.insert("bb", 2).unwrap();
+    table.insert("ccc", 3).unwrap();
+    let mut entries: Vec<_> = table.iter().map(|(k, v)| (*k, *v)).collect();
+    entries.sort();
+    assert_eq!(entries, [("a", 1), ("bb", 2), ("ccc", 3)]);
+    assert_eq!((&table).into_iter().len(), 3);
+    table.clear();
+    assert!(table.is_empty());
+    assert_eq!(table.iter().next(), None);
+}
+
+
+#[test]
+fn test_iter_mut_into_iter_and_clone() {
+    let mut table = StaticHashTable::<u32, String, U7>::new();
+    for k in 0..5 {
+        table.insert(k, k.to_string()).unwrap();
+    }
+    for (k, v) in table.iter_mut() {
+        v.push_str(if k % 2 == 0 { "e" } else { "o" });
+    }
+    for (_, v) in &mut table {
+        v.push('!');
+    }
+    let copy = table.clone();
+    assert_eq!(copy.get(&3).map(|v| &v[..]), Some("3o!"));
+    let mut entries: Vec<_> = table.into_iter().collect();
+    entries.sort();
+    assert_eq!(entries, [(0, "0e!".to_string()), (1, "1o!".to_string()), (2, "2e!".to_string()),
+                         (3, "3o!".to_string()), (4, "4e!".to_string())]);
+    assert_eq!(copy.into_iter().len(), 5);
+}
+
+
+#[test]
+fn test_two_buckets() {
+    let mut table = StaticHashTable::<u8, (), U2>::new();
+    table.insert(1, ()).unwrap();
+    table.insert(2, ()).unwrap();
+    assert_eq!(table.insert(3, ()), Err((3, ())));
+    assert!(table.contains_key(&1) && table.contains_key(&2));
+}
+
+
+#[test]
+fn test_churn() {
+    // Every key is inserted once and removed again, so without
+    // rehashing the deleted markers would fill the whole table.
+    let mut table = StaticHashTable::<u64, u64, U31>::new();
+    for k in 0..10_000 {
+        assert_eq!(table.insert(k, k), Ok(None));
+        if k >= 20 {
+            assert_eq!(table.remove(&(k - 20)), Some(k - 20));
+        }
+        assert_eq!(table.len(), (k + 1).min(20) as usize);
+    }
+    for k in 0..10_000 {
+        assert_eq!(table.get(&k), if k < 9_980 { None } else { Some(&k) });
+    }
+    assert_eq!(table.iter().count(), 20);
+}
@@ -9,13 +9,14 @@
 // modified, or distributed except according to those terms.
 
 extern crate typenum;
-extern crate typenum_prime;
+#[macro_use] extern crate typenum_prime;
 
 use typenum::consts::*;
 use typenum::bit::Bit;
+use typenum::marker_traits::Unsigned;
 use typenum::operator_aliases::{Shleft, Sub1};
 
-use typenum_prime::IsPrime;
+use typenum_prime::{IsPrime, is_prime_u64};
 
 
 // Test mersenne primes only because they are easy to succinctly
@@ -25,6 +26,10 @@ use typenum_prime::IsPrime;
 type MersenneNumber<N> = Sub1<Shleft<U1, N>>;
 
 
+assert_prime!(MersenneNumber<U13>);
+assert_composite!(MersenneNumber<U11>);
+
+
 #[test]
 fn test_larger_mersenne_primes() {
     assert!(<MersenneNumber<U7> as IsPrime>::Output::to_bool()); // 127
@@ -34,3 +39,15 @@ fn test_larger_mersenne_primes() {
     // This would overflow the default compiler recursion level:
     //assert!(<MersenneNumber<U31> as IsPrime>::Output::to_bool()); // 2_147_483_647
 }
+
+
+#[test]
+fn test_runtime_agrees() {
+    assert!(is_prime_u64(MersenneNumber::<U7>::to_u64()));
+    assert!(is_prime_u64(MersenneNumber::<U13>::to_u64()));
+    assert!(is_prime_u64(MersenneNumber::<U17>::to_u64()));
+    assert!(is_prime_u64(MersenneNumber::<U19>::to_u64()));
+    assert!(!is_prime_u64(MersenneNumber::<U11>::to_u64())); // 2_047 == 23*89
+    assert!(is_prime_u64(MersenneNumber::<U31>::to_u64()));
+    assert!(is_prime_u64(MersenneNumber::<U61>::to_u64()));
+}
@@ -0,0 +1,62 @@
+// test/macros.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "macros")]
+#![recursion_limit = "256"]
+
+#[macro_use] extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::*;
+use typenum::marker_traits::Unsigned;
+use typenum::operator_aliases::{Shleft, Sub1};
+
+use typenum_prime::{Barrett, CertifiedPrime, Fp, Montgomery, Prime, prime};
+
+
+fn checked_prime<N: Prime>() -> u64 {
+    N::to_u64()
+}
+
+fn certified_prime<P: CertifiedPrime>() -> u64 {
+    P::Value::to_u64()
+}
+
+
+#[test]
+fn test_small_primes() {
+    assert_type_eq!(prime!(2), U2);
+    assert_type_eq!(prime!(127), U127);
+    assert_type_eq!(prime!(0x3fd), U1021);
+    assert_eq!(checked_prime::<prime!(97)>(), 97);
+    assert_eq!(certified_prime::<prime!(97)>(), 97);
+}
+
+
+#[test]
+fn test_large_primes() {
+    assert_eq!(certified_prime::<prime!(1031)>(), 1031);
+    assert_eq!(certified_prime::<prime!(2_147_483_647u64)>(), 2_147_483_647);
+    // far beyond trial division, but slow to check
+    assert_type_eq!(<prime!(2_305_843_009_213_693_951) as CertifiedPrime>::Value,
+                    Sub1<Shleft<U1, U61>>);
+}
+
+
+#[test]
+fn test_field_types() {
+    // 2^61 - 1, so that 2^61 is 1
+    type P = prime!(2_305_843_009_213_693_951);
+    let a = Fp::<P>::new(1 << 60);
+    assert_eq!((a * a).value(), 1 << 59);
+    assert_eq!(a * a.inv().unwrap(), Fp::ONE);
+    assert_eq!(Fp::from(Montgomery::<P>::new(1 << 60).pow(2)), a * a);
+    assert_eq!(Barrett::<P>::new().reduce(1 << 122), 1);
+}
@@ -0,0 +1,25 @@
+// test/macros_renamed.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "macros")]
+
+// `prime!` must not assume that the crate is imported as
+// `typenum_prime`.
+#[macro_use] extern crate typenum_prime as tp;
+
+use tp::typenum::marker_traits::Unsigned;
+use tp::CertifiedPrime;
+
+
+#[test]
+fn test_renamed_crate() {
+    assert_eq!(<prime!(97) as CertifiedPrime>::Value::to_u64(), 97);
+    assert_eq!(<prime!(65_537) as CertifiedPrime>::Value::to_u64(), 65_537);
+}
@@ -0,0 +1,90 @@
+// test/ntt.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "generic-array")]
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::*;
+use typenum::marker_traits::Unsigned;
+use typenum::operator_aliases::{Add1, Prod};
+
+use typenum_prime::generic_array::GenericArray;
+use typenum_prime::generic_array::sequence::GenericSequence;
+use typenum_prime::generic_array::functional::FunctionalSequence;
+use typenum_prime::{inverse_ntt, ntt, Fp, RootOfUnity};
+
+
+// 15*2^9 + 1
+type P7681 = Add1<Prod<U15, U512>>;
+
+// 3*2^12 + 1
+type P12289 = Add1<Prod<U3, U4096>>;
+
+
+// A pseudo-random element of GF(P) for index i.
+fn sample<P: typenum_prime::Prime>(i: usize) -> Fp<P> {
+    Fp::new((i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 20)
+}
+
+
+#[test]
+fn test_matches_naive_dft() {
+    type P = P7681;
+    let mut values = GenericArray::<Fp<P>, U16>::generate(sample);
+    let input = values;
+    ntt(&mut values);
+    let root = Fp::<P>::new(<P as RootOfUnity<U4>>::Output::U64);
+    for k in 0..16 {
+        let expected = (0..16).fold(Fp::ZERO, |acc, j| acc + input[j] * root.pow((j * k) as u64));
+        assert_eq!(values[k], expected);
+    }
+}
+
+
+#[test]
+fn test_round_trip() {
+    let mut values = GenericArray::<Fp<P7681>, U512>::generate(sample);
+    let input = values;
+    ntt(&mut values);
+    assert_ne!(values, input);
+    inverse_ntt(&mut values);
+    assert_eq!(values, input);
+
+    // the trivial lengths
+    let mut one = GenericArray::<Fp<U17>, U1>::generate(|_| Fp::new(5));
+    ntt(&mut one);
+    assert_eq!(one[0], Fp::new(5));
+    let mut two = GenericArray::<Fp<U3>, U2>::generate(|i| Fp::new(i as u64 + 1));
+    ntt(&mut two);
+    assert_eq!(two.as_slice(), &[Fp::new(0), Fp::new(2)]);
+}
+
+
+#[test]
+fn test_polynomial_product() {
+    type P = P12289;
+    let a = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+    let b = [2, 7, 1, 8, 2, 8, 1, 8, 2, 8, 4, 5, 9, 0, 4, 5];
+    let mut expected = [0u64; 32];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            expected[i + j] += x * y;
+        }
+    }
+    let pad = |coeffs: &[u64]| GenericArray::<Fp<P>, U32>::generate(|i| Fp::new(*coeffs.get(i).unwrap_or(&0)));
+    let (mut fa, mut fb) = (pad(&a), pad(&b));
+    ntt(&mut fa);
+    ntt(&mut fb);
+    let mut product = fa.zip(fb, |x, y| x * y);
+    inverse_ntt(&mut product);
+    assert_eq!(product, pad(&expected));
+}
@@ -0,0 +1,70 @@
+// test/prime_factor.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "generic-array")]
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::*;
+use typenum_prime::generic_array::GenericArray;
+use typenum_prime::generic_array::sequence::GenericSequence;
+use typenum_prime::{rader_dft, Fp, PrimeFactorMap};
+
+
+#[test]
+fn test_good_thomas_matches_naive_dft() {
+    // 3 is a primitive root modulo 31, so 3^2 has order 15
+    let root = Fp::<U31>::new(9);
+    let map = PrimeFactorMap::<U3, U5>::new();
+    let input = GenericArray::<Fp<U31>, U15>::generate(|i| Fp::new((i * i + 4) as u64));
+
+    // no twiddle factors between the row and column transforms
+    let mut grid = map.gather_input(&input);
+    for n1 in 0..3 {
+        let mut row = GenericArray::<Fp<U31>, U5>::generate(|n2| grid[n1 * 5 + n2]);
+        rader_dft(&mut row, |k| root.pow(3 * k as u64));
+        for k2 in 0..5 {
+            grid[n1 * 5 + k2] = row[k2];
+        }
+    }
+    for k2 in 0..5 {
+        let mut column = GenericArray::<Fp<U31>, U3>::generate(|n1| grid[n1 * 5 + k2]);
+        rader_dft(&mut column, |k| root.pow(5 * k as u64));
+        for k1 in 0..3 {
+            grid[k1 * 5 + k2] = column[k1];
+        }
+    }
+    let output = map.scatter_output(&grid);
+
+    for k in 0..15 {
+        let expected = (0..15).fold(Fp::ZERO, |acc, j| acc + input[j] * root.pow((j * k) as u64));
+        assert_eq!(output[k], expected, "k = {}", k);
+    }
+}
+
+
+#[test]
+fn test_gather_and_scatter_permute() {
+    let map = PrimeFactorMap::<U4, U9>::new();
+    let values = GenericArray::<usize, U36>::generate(|i| i);
+    let grid = map.gather_input(&values);
+    for n1 in 0..4 {
+        for n2 in 0..9 {
+            assert_eq!(grid[n1 * 9 + n2], map.input_index(n1, n2));
+        }
+    }
+    let output = map.scatter_output(&values);
+    for k in 0..36 {
+        let (k1, k2) = map.output_coords(k);
+        assert_eq!(output[k], k1 * 9 + k2);
+        assert_eq!(map.output_index(k1, k2), k);
+    }
+}
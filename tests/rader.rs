@@ -0,0 +1,120 @@
+// test/rader.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+#![cfg(feature = "generic-array")]
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use std::f64::consts::PI;
+use std::ops::{Add, Mul};
+
+use typenum::consts::*;
+
+use typenum_prime::generic_array::{ArrayLength, GenericArray};
+use typenum_prime::generic_array::sequence::GenericSequence;
+use typenum_prime::{rader_dft, rader_dft_with, Fp, PrimitiveRoot};
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    // e^(-2πik/n)
+    fn twiddle(k: usize, n: usize) -> Complex {
+        let angle = -2.0 * PI * k as f64 / n as f64;
+        Complex { re: angle.cos(), im: angle.sin() }
+    }
+
+    fn distance(self, other: Complex) -> f64 {
+        (self.re - other.re).hypot(self.im - other.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+
+fn check_complex<N>() where N: PrimitiveRoot + ArrayLength {
+    let n = N::USIZE;
+    let input = GenericArray::<Complex,N>::generate(|i| Complex { re: (i * i % 7) as f64, im: i as f64 - 2.5 });
+    let mut values = input.clone();
+    rader_dft(&mut values, |k| Complex::twiddle(k, n));
+    for k in 0..n {
+        let expected = (0..n).fold(Complex::default(), |acc, j| acc + input[j] * Complex::twiddle(j * k % n, n));
+        assert!(values[k].distance(expected) < 1e-9, "N = {}, k = {}", n, k);
+    }
+}
+
+
+#[test]
+fn test_complex_matches_naive_dft() {
+    check_complex::<U2>();
+    check_complex::<U3>();
+    check_complex::<U5>();
+    check_complex::<U7>();
+    check_complex::<U13>();
+    check_complex::<U31>();
+    check_complex::<U101>();
+}
+
+
+#[test]
+fn test_finite_field() {
+    // 2 is a primitive root modulo 29, so 2^4 has order 7
+    let root = Fp::<U29>::new(16);
+    let input = GenericArray::<Fp<U29>, U7>::generate(|i| Fp::new(3 * i as u64 + 1));
+    let mut values = input;
+    rader_dft(&mut values, |k| root.pow(k as u64));
+    for k in 0..7 {
+        let expected = (0..7).fold(Fp::ZERO, |acc, j| acc + input[j] * root.pow((j * k) as u64));
+        assert_eq!(values[k], expected);
+    }
+}
+
+
+#[test]
+fn test_custom_convolution() {
+    // the convolution is handed the permuted data for any method to
+    // compute
+    let mut calls = 0;
+    let mut values = GenericArray::<Complex, U11>::generate(|i| Complex { re: i as f64, im: 0.0 });
+    let input = values;
+    rader_dft_with(&mut values, |k| Complex::twiddle(k, 11), |signal, kernel, out| {
+        calls += 1;
+        assert_eq!((signal.len(), kernel.len(), out.len()), (10, 10, 10));
+        for p in 0..10 {
+            out[p] = (0..10).fold(Complex::default(), |acc, q| acc + signal[q] * kernel[(10 + p - q) % 10]);
+        }
+    });
+    assert_eq!(calls, 1);
+    let mut expected = input;
+    rader_dft(&mut expected, |k| Complex::twiddle(k, 11));
+    assert_eq!(values, expected);
+}
@@ -0,0 +1,24 @@
+// test/ui.rs
+//
+// Copyright (c) 2018 David Creswick
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+extern crate rustversion;
+extern crate trybuild;
+
+
+// The expected compiler output in tests/ui/*.stderr changes between
+// compiler versions. Regenerate it with `TRYBUILD=overwrite cargo test
+// --test ui` when a new stable release changes it.
+#[rustversion::attr(before(1.95), ignore)]
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/prime_from_is_prime.rs");
+    t.compile_fail("tests/ui/prime_names_divisor.rs");
+}
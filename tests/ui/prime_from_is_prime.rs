@@ -0,0 +1,17 @@
+// A bound on the result of IsPrime implies Prime in generic code.
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::{True, U97};
+use typenum_prime::{IsPrime, Prime};
+
+fn buckets<N: Prime>() {}
+
+fn checked_buckets<N: IsPrime<Output=True>>() {
+    buckets::<N>();
+}
+
+fn main() {
+    checked_buckets::<U97>();
+}
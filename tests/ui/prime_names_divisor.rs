@@ -0,0 +1,13 @@
+// The error for a composite integer names its smallest prime divisor.
+
+extern crate typenum;
+extern crate typenum_prime;
+
+use typenum::consts::U91;
+use typenum_prime::Prime;
+
+fn buckets<N: Prime>() {}
+
+fn main() {
+    buckets::<U91>();
+}
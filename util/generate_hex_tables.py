@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+#
+# util/generate_hex_tables.py
+#
+# Copyright (c) 2018 David Creswick
+#
+# Licensed under the Apache License, Version 2.0
+# <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+# license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. All files in the project carrying such notice may not be copied,
+# modified, or distributed except according to those terms.
+
+# Arithmetic tables for single hexadecimal digits, which the checker
+# of primality certificates builds multi-digit arithmetic on.
+
+print("// src/private/hex.rs")
+print("//")
+print("// Copyright (c) 2018 David Creswick")
+print("//")
+print("// Licensed under the Apache License, Version 2.0")
+print("// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT")
+print("// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your")
+print("// option. All files in the project carrying such notice may not be copied,")
+print("// modified, or distributed except according to those terms.")
+print()
+print("// DO NOT EDIT THIS FILE DIRECTLY!")
+print("// This file is the output of util/generate_hex_tables.py")
+print("use typenum::bit::{B0, B1};")
+print()
+print("// The hexadecimal digits.")
+for d in range(16):
+    print("pub struct H%d;" % d)
+print()
+print("// A two-digit number, most significant digit first.")
+print("pub struct Byte<Hi,Lo>(Hi, Lo);")
+print()
+print("// Self*Rhs.")
+print("pub trait HexMul<Rhs> {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    for b in range(16):
+        print("impl HexMul<H%d> for H%d { type Output = Byte<H%d,H%d>; }"
+              % (b, a, a*b // 16, a*b % 16))
+print()
+print("// Self + Rhs, as the carry out and the sum digit.")
+print("pub trait HexAdd<Rhs> {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    for b in range(16):
+        print("impl HexAdd<H%d> for H%d { type Output = (B%d, H%d); }"
+              % (b, a, int(a + b > 15), (a + b) % 16))
+print()
+print("// Self + Carry, which is at most 0xf.")
+print("pub trait HexCarry<Carry> {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    print("impl HexCarry<B0> for H%d { type Output = H%d; }" % (a, a))
+    if a < 15:
+        print("impl HexCarry<B1> for H%d { type Output = H%d; }" % (a, a + 1))
+print()
+# Each table above has at most 32 impls for a given Self type, which
+# keeps the impl candidates that rustc tries for each digit few. The
+# generic impls below combine them.
+print("// Self + C, where the sum is at most 0xff.")
+print("pub trait ByteAdd<C> {")
+print("    type Output;")
+print("}")
+print()
+print("impl<Hi,Lo,C> ByteAdd<C> for Byte<Hi,Lo>")
+print("    where Lo: HexAdd<C>,")
+print("          <Lo as HexAdd<C>>::Output: ByteAddCarry<Hi> {")
+print("    type Output = <<Lo as HexAdd<C>>::Output as ByteAddCarry<Hi>>::Output;")
+print("}")
+print()
+print("// The rest of ByteAdd, where Self is the carry and the low digit.")
+print("pub trait ByteAddCarry<Hi> {")
+print("    type Output;")
+print("}")
+print()
+print("impl<Hi,Carry,Lo> ByteAddCarry<Hi> for (Carry, Lo) where Hi: HexCarry<Carry> {")
+print("    type Output = Byte<<Hi as HexCarry<Carry>>::Output,Lo>;")
+print("}")
+print()
+print("// Self*D + A, which is at most 0xff.")
+print("pub trait HexMulAdd<D,A> {")
+print("    type Output;")
+print("}")
+print()
+print("impl<X,D,A> HexMulAdd<D,A> for X")
+print("    where X: HexMul<D>,")
+print("          <X as HexMul<D>>::Output: ByteAdd<A> {")
+print("    type Output = <<X as HexMul<D>>::Output as ByteAdd<A>>::Output;")
+print("}")
+print()
+print("// Self - Rhs - Borrow, as the borrow out and the difference digit.")
+print("pub trait HexSub<Rhs,Borrow> {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    for b in range(16):
+        for c in range(2):
+            s = a - b - c
+            print("impl HexSub<H%d,B%d> for H%d { type Output = (B%d, H%d); }"
+                  % (b, c, a, int(s < 0), s % 16))
+print()
+print("// Self*Rhs modulo 16.")
+print("pub trait HexMulLow<Rhs> {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    for b in range(16):
+        print("impl HexMulLow<H%d> for H%d { type Output = H%d; }" % (b, a, a*b % 16))
+print()
+print("// -1/Self modulo 16, for odd digits.")
+print("pub trait HexNegInv {")
+print("    type Output;")
+print("}")
+for a in range(1, 16, 2):
+    inv = next(x for x in range(16) if a*x % 16 == 15)
+    print("impl HexNegInv for H%d { type Output = H%d; }" % (a, inv))
+print()
+print("// The digit with the given four bits, most significant first.")
+print("pub trait HexOfBits {")
+print("    type Output;")
+print("}")
+for a in range(16):
+    bits = tuple((a >> k) & 1 for k in (3, 2, 1, 0))
+    print("impl HexOfBits for (B%d, B%d, B%d, B%d) { type Output = H%d; }" % (bits + (a,)))
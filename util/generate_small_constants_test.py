@@ -45,3 +45,11 @@ for (test_name, b) in [("prime", True),
             #print("    assert!(%s<U%d as IsPrime>::Output::to_bool());"%(s,n))
             print("    assert_type_eq!(<U%d as IsPrime>::Output, %s);"%(n,b))
     print("}")
+
+print()
+print("#[test]")
+print("fn test_runtime() {")
+for n in range(N+1):
+    print("    assert_eq!(is_prime_u64(%d), <U%d as IsPrime>::Output::to_bool());"%(n,n))
+    print("    assert_eq!(is_prime_u128(%d), <U%d as IsPrime>::Output::to_bool());"%(n,n))
+print("}")